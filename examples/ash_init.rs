@@ -19,7 +19,12 @@ fn main() {
   }
 
   let entry = unsafe {
-    Entry::from_static_fn(StaticFn { get_instance_proc_addr: core::mem::transmute(pfn) })
+    Entry::from_static_fn(StaticFn {
+      get_instance_proc_addr: core::mem::transmute::<
+        *mut core::ffi::c_void,
+        ash::vk::PFN_vkGetInstanceProcAddr,
+      >(pfn),
+    })
   };
   let app_info = ash::vk::ApplicationInfo {
     api_version: ash::vk::make_api_version(0, 1, 0, 0),
@@ -0,0 +1,41 @@
+use beryllium::{events::Event, init::InitFlags, video::CreateWinArgs, Sdl};
+use pixel_formats::r8g8b8a8_Srgb;
+
+fn main() {
+  // Initializes SDL2
+  let sdl = Sdl::init(InitFlags::EVERYTHING);
+
+  // Makes a plain window, which we'll draw to on the CPU.
+  let mut win = sdl
+    .create_window(CreateWinArgs { title: "Example Surface Window", ..Default::default() })
+    .unwrap();
+
+  let pix_buf = [r8g8b8a8_Srgb { r: 255, g: 127, b: 16, a: 255 }; 64];
+  let surface = sdl.create_surface_from(&pix_buf, 8, 8).unwrap();
+
+  // program "main loop".
+  'the_loop: loop {
+    // Process events from this frame.
+    #[allow(clippy::never_loop)]
+    #[allow(clippy::single_match)]
+    while let Some((event, _timestamp)) = sdl.poll_events() {
+      match event {
+        Event::Quit => break 'the_loop,
+        _ => (),
+      }
+    }
+
+    let mut win_surface = win.get_window_surface().unwrap();
+    // Setting every byte of a row to the same value gives gray, whatever the
+    // surface's pixel format is.
+    let pitch = win_surface.pitch() as usize;
+    for (y, row) in win_surface.pixels_mut().chunks_exact_mut(pitch).enumerate() {
+      row.fill((y % 256) as u8);
+    }
+    win_surface.blit(&surface, 200, 300).unwrap();
+
+    win.update_window_surface().unwrap();
+  }
+
+  // All the cleanup is handled by the various drop impls.
+}
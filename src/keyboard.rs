@@ -0,0 +1,839 @@
+use alloc::string::String;
+use core::{marker::PhantomData, ops::Index};
+use fermium::prelude::*;
+
+use crate::{string_from_sdl, video::CommonWindow, Sdl};
+
+impl Sdl {
+  /// Starts accepting Unicode text input events.
+  ///
+  /// While text input is active you'll get [`TextInput`] and [`TextEditing`]
+  /// events, and on platforms with an on-screen keyboard it will be shown.
+  ///
+  /// [`TextInput`]: crate::events::Event::TextInput
+  /// [`TextEditing`]: crate::events::Event::TextEditing
+  #[inline]
+  pub fn start_text_input(&self) {
+    unsafe { SDL_StartTextInput() }
+  }
+
+  /// Stops accepting Unicode text input events.
+  #[inline]
+  pub fn stop_text_input(&self) {
+    unsafe { SDL_StopTextInput() }
+  }
+
+  #[inline]
+  pub fn is_text_input_active(&self) -> bool {
+    unsafe { SDL_IsTextInputActive() }.into()
+  }
+
+  /// Sets the `[x, y, w, h]` area where text is being entered.
+  ///
+  /// This is used as a hint for the placement of the IME candidate list.
+  #[inline]
+  pub fn set_text_input_rect(&self, rect: [c_int; 4]) {
+    let mut rect = rect;
+    unsafe { SDL_SetTextInputRect(rect.as_mut_ptr().cast::<SDL_Rect>()) }
+  }
+
+  /// If the platform supports an on-screen keyboard at all.
+  #[inline]
+  pub fn has_screen_keyboard_support(&self) -> bool {
+    unsafe { SDL_HasScreenKeyboardSupport() }.into()
+  }
+
+  /// If the on-screen keyboard is currently shown for the window given.
+  #[inline]
+  pub fn is_screen_keyboard_shown(&self, win: &CommonWindow) -> bool {
+    unsafe { SDL_IsScreenKeyboardShown(win.win.as_ptr()) }.into()
+  }
+
+  /// Allows long IME composition text to be delivered in full.
+  ///
+  /// Without this, composition text longer than the normal event buffer is
+  /// truncated. Either way it arrives as [`TextEditing`].
+  ///
+  /// [`TextEditing`]: crate::events::Event::TextEditing
+  #[inline]
+  pub fn set_ime_support_extended_text(&self, extended: bool) -> bool {
+    const SDL_HINT_IME_SUPPORT_EXTENDED_TEXT: &[u8] = b"SDL_IME_SUPPORT_EXTENDED_TEXT\0";
+    let value: &[u8] = if extended { b"1\0" } else { b"0\0" };
+    unsafe {
+      SDL_SetHint(SDL_HINT_IME_SUPPORT_EXTENDED_TEXT.as_ptr().cast(), value.as_ptr().cast())
+    }
+    .into()
+  }
+}
+
+/// A raw key value that doesn't have a named [`Scancode`] or [`Keycode`]
+/// variant.
+///
+/// These can only be made by converting from SDL's values, which keeps them
+/// from overlapping the named variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnknownKey(i32);
+impl UnknownKey {
+  /// The raw SDL value.
+  #[inline]
+  pub fn get(self) -> i32 {
+    self.0
+  }
+}
+
+/// Declares a key enum that converts to and from the matching `fermium` type.
+macro_rules! key_enum {
+  (
+    $(#[$meta:meta])*
+    pub enum $name:ident($sdl:ident) {
+      $($variant:ident = $konst:ident,)*
+    }
+  ) => {
+    $(#[$meta])*
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum $name {
+      $($variant,)*
+      /// Any value that doesn't have a named variant.
+      ///
+      /// This is never used for a value that does have a named variant, so
+      /// each key has only one representation.
+      Unknown(UnknownKey),
+    }
+    impl $name {
+      /// Converts a raw SDL value, using the named variant if there is one.
+      #[inline]
+      pub fn from_raw(value: i32) -> Self {
+        Self::from($sdl(value))
+      }
+    }
+    impl From<$sdl> for $name {
+      #[inline]
+      #[allow(non_upper_case_globals)]
+      fn from(value: $sdl) -> Self {
+        match value {
+          $($konst => Self::$variant,)*
+          other => Self::Unknown(UnknownKey(other.0)),
+        }
+      }
+    }
+    impl From<$name> for $sdl {
+      #[inline]
+      fn from(value: $name) -> Self {
+        match value {
+          $($name::$variant => $konst,)*
+          $name::Unknown(UnknownKey(i)) => $sdl(i),
+        }
+      }
+    }
+  };
+}
+
+key_enum! {
+  /// A physical key location, independent of the keyboard layout.
+  ///
+  /// Use this for things like "WASD" movement, where the position of the key
+  /// matters more than what's printed on it.
+  pub enum Scancode(SDL_Scancode) {
+    A = SDL_SCANCODE_A,
+    B = SDL_SCANCODE_B,
+    C = SDL_SCANCODE_C,
+    D = SDL_SCANCODE_D,
+    E = SDL_SCANCODE_E,
+    F = SDL_SCANCODE_F,
+    G = SDL_SCANCODE_G,
+    H = SDL_SCANCODE_H,
+    I = SDL_SCANCODE_I,
+    J = SDL_SCANCODE_J,
+    K = SDL_SCANCODE_K,
+    L = SDL_SCANCODE_L,
+    M = SDL_SCANCODE_M,
+    N = SDL_SCANCODE_N,
+    O = SDL_SCANCODE_O,
+    P = SDL_SCANCODE_P,
+    Q = SDL_SCANCODE_Q,
+    R = SDL_SCANCODE_R,
+    S = SDL_SCANCODE_S,
+    T = SDL_SCANCODE_T,
+    U = SDL_SCANCODE_U,
+    V = SDL_SCANCODE_V,
+    W = SDL_SCANCODE_W,
+    X = SDL_SCANCODE_X,
+    Y = SDL_SCANCODE_Y,
+    Z = SDL_SCANCODE_Z,
+    Num1 = SDL_SCANCODE_1,
+    Num2 = SDL_SCANCODE_2,
+    Num3 = SDL_SCANCODE_3,
+    Num4 = SDL_SCANCODE_4,
+    Num5 = SDL_SCANCODE_5,
+    Num6 = SDL_SCANCODE_6,
+    Num7 = SDL_SCANCODE_7,
+    Num8 = SDL_SCANCODE_8,
+    Num9 = SDL_SCANCODE_9,
+    Num0 = SDL_SCANCODE_0,
+    Return = SDL_SCANCODE_RETURN,
+    Escape = SDL_SCANCODE_ESCAPE,
+    Backspace = SDL_SCANCODE_BACKSPACE,
+    Tab = SDL_SCANCODE_TAB,
+    Space = SDL_SCANCODE_SPACE,
+    Minus = SDL_SCANCODE_MINUS,
+    Equals = SDL_SCANCODE_EQUALS,
+    LeftBracket = SDL_SCANCODE_LEFTBRACKET,
+    RightBracket = SDL_SCANCODE_RIGHTBRACKET,
+    Backslash = SDL_SCANCODE_BACKSLASH,
+    NonUsHash = SDL_SCANCODE_NONUSHASH,
+    Semicolon = SDL_SCANCODE_SEMICOLON,
+    Apostrophe = SDL_SCANCODE_APOSTROPHE,
+    Grave = SDL_SCANCODE_GRAVE,
+    Comma = SDL_SCANCODE_COMMA,
+    Period = SDL_SCANCODE_PERIOD,
+    Slash = SDL_SCANCODE_SLASH,
+    CapsLock = SDL_SCANCODE_CAPSLOCK,
+    F1 = SDL_SCANCODE_F1,
+    F2 = SDL_SCANCODE_F2,
+    F3 = SDL_SCANCODE_F3,
+    F4 = SDL_SCANCODE_F4,
+    F5 = SDL_SCANCODE_F5,
+    F6 = SDL_SCANCODE_F6,
+    F7 = SDL_SCANCODE_F7,
+    F8 = SDL_SCANCODE_F8,
+    F9 = SDL_SCANCODE_F9,
+    F10 = SDL_SCANCODE_F10,
+    F11 = SDL_SCANCODE_F11,
+    F12 = SDL_SCANCODE_F12,
+    PrintScreen = SDL_SCANCODE_PRINTSCREEN,
+    ScrollLock = SDL_SCANCODE_SCROLLLOCK,
+    Pause = SDL_SCANCODE_PAUSE,
+    Insert = SDL_SCANCODE_INSERT,
+    Home = SDL_SCANCODE_HOME,
+    PageUp = SDL_SCANCODE_PAGEUP,
+    Delete = SDL_SCANCODE_DELETE,
+    End = SDL_SCANCODE_END,
+    PageDown = SDL_SCANCODE_PAGEDOWN,
+    Right = SDL_SCANCODE_RIGHT,
+    Left = SDL_SCANCODE_LEFT,
+    Down = SDL_SCANCODE_DOWN,
+    Up = SDL_SCANCODE_UP,
+    NumLockClear = SDL_SCANCODE_NUMLOCKCLEAR,
+    KpDivide = SDL_SCANCODE_KP_DIVIDE,
+    KpMultiply = SDL_SCANCODE_KP_MULTIPLY,
+    KpMinus = SDL_SCANCODE_KP_MINUS,
+    KpPlus = SDL_SCANCODE_KP_PLUS,
+    KpEnter = SDL_SCANCODE_KP_ENTER,
+    Kp1 = SDL_SCANCODE_KP_1,
+    Kp2 = SDL_SCANCODE_KP_2,
+    Kp3 = SDL_SCANCODE_KP_3,
+    Kp4 = SDL_SCANCODE_KP_4,
+    Kp5 = SDL_SCANCODE_KP_5,
+    Kp6 = SDL_SCANCODE_KP_6,
+    Kp7 = SDL_SCANCODE_KP_7,
+    Kp8 = SDL_SCANCODE_KP_8,
+    Kp9 = SDL_SCANCODE_KP_9,
+    Kp0 = SDL_SCANCODE_KP_0,
+    KpPeriod = SDL_SCANCODE_KP_PERIOD,
+    NonUsBackslash = SDL_SCANCODE_NONUSBACKSLASH,
+    Application = SDL_SCANCODE_APPLICATION,
+    Power = SDL_SCANCODE_POWER,
+    KpEquals = SDL_SCANCODE_KP_EQUALS,
+    F13 = SDL_SCANCODE_F13,
+    F14 = SDL_SCANCODE_F14,
+    F15 = SDL_SCANCODE_F15,
+    F16 = SDL_SCANCODE_F16,
+    F17 = SDL_SCANCODE_F17,
+    F18 = SDL_SCANCODE_F18,
+    F19 = SDL_SCANCODE_F19,
+    F20 = SDL_SCANCODE_F20,
+    F21 = SDL_SCANCODE_F21,
+    F22 = SDL_SCANCODE_F22,
+    F23 = SDL_SCANCODE_F23,
+    F24 = SDL_SCANCODE_F24,
+    Execute = SDL_SCANCODE_EXECUTE,
+    Help = SDL_SCANCODE_HELP,
+    Menu = SDL_SCANCODE_MENU,
+    Select = SDL_SCANCODE_SELECT,
+    Stop = SDL_SCANCODE_STOP,
+    Again = SDL_SCANCODE_AGAIN,
+    Undo = SDL_SCANCODE_UNDO,
+    Cut = SDL_SCANCODE_CUT,
+    Copy = SDL_SCANCODE_COPY,
+    Paste = SDL_SCANCODE_PASTE,
+    Find = SDL_SCANCODE_FIND,
+    Mute = SDL_SCANCODE_MUTE,
+    VolumeUp = SDL_SCANCODE_VOLUMEUP,
+    VolumeDown = SDL_SCANCODE_VOLUMEDOWN,
+    KpComma = SDL_SCANCODE_KP_COMMA,
+    KpEqualsAs400 = SDL_SCANCODE_KP_EQUALSAS400,
+    International1 = SDL_SCANCODE_INTERNATIONAL1,
+    International2 = SDL_SCANCODE_INTERNATIONAL2,
+    International3 = SDL_SCANCODE_INTERNATIONAL3,
+    International4 = SDL_SCANCODE_INTERNATIONAL4,
+    International5 = SDL_SCANCODE_INTERNATIONAL5,
+    International6 = SDL_SCANCODE_INTERNATIONAL6,
+    International7 = SDL_SCANCODE_INTERNATIONAL7,
+    International8 = SDL_SCANCODE_INTERNATIONAL8,
+    International9 = SDL_SCANCODE_INTERNATIONAL9,
+    Lang1 = SDL_SCANCODE_LANG1,
+    Lang2 = SDL_SCANCODE_LANG2,
+    Lang3 = SDL_SCANCODE_LANG3,
+    Lang4 = SDL_SCANCODE_LANG4,
+    Lang5 = SDL_SCANCODE_LANG5,
+    Lang6 = SDL_SCANCODE_LANG6,
+    Lang7 = SDL_SCANCODE_LANG7,
+    Lang8 = SDL_SCANCODE_LANG8,
+    Lang9 = SDL_SCANCODE_LANG9,
+    AltErase = SDL_SCANCODE_ALTERASE,
+    SysReq = SDL_SCANCODE_SYSREQ,
+    Cancel = SDL_SCANCODE_CANCEL,
+    Clear = SDL_SCANCODE_CLEAR,
+    Prior = SDL_SCANCODE_PRIOR,
+    Return2 = SDL_SCANCODE_RETURN2,
+    Separator = SDL_SCANCODE_SEPARATOR,
+    Out = SDL_SCANCODE_OUT,
+    Oper = SDL_SCANCODE_OPER,
+    ClearAgain = SDL_SCANCODE_CLEARAGAIN,
+    CrSel = SDL_SCANCODE_CRSEL,
+    ExSel = SDL_SCANCODE_EXSEL,
+    Kp00 = SDL_SCANCODE_KP_00,
+    Kp000 = SDL_SCANCODE_KP_000,
+    ThousandsSeparator = SDL_SCANCODE_THOUSANDSSEPARATOR,
+    DecimalSeparator = SDL_SCANCODE_DECIMALSEPARATOR,
+    CurrencyUnit = SDL_SCANCODE_CURRENCYUNIT,
+    CurrencySubUnit = SDL_SCANCODE_CURRENCYSUBUNIT,
+    KpLeftParen = SDL_SCANCODE_KP_LEFTPAREN,
+    KpRightParen = SDL_SCANCODE_KP_RIGHTPAREN,
+    KpLeftBrace = SDL_SCANCODE_KP_LEFTBRACE,
+    KpRightBrace = SDL_SCANCODE_KP_RIGHTBRACE,
+    KpTab = SDL_SCANCODE_KP_TAB,
+    KpBackspace = SDL_SCANCODE_KP_BACKSPACE,
+    KpA = SDL_SCANCODE_KP_A,
+    KpB = SDL_SCANCODE_KP_B,
+    KpC = SDL_SCANCODE_KP_C,
+    KpD = SDL_SCANCODE_KP_D,
+    KpE = SDL_SCANCODE_KP_E,
+    KpF = SDL_SCANCODE_KP_F,
+    KpXor = SDL_SCANCODE_KP_XOR,
+    KpPower = SDL_SCANCODE_KP_POWER,
+    KpPercent = SDL_SCANCODE_KP_PERCENT,
+    KpLess = SDL_SCANCODE_KP_LESS,
+    KpGreater = SDL_SCANCODE_KP_GREATER,
+    KpAmpersand = SDL_SCANCODE_KP_AMPERSAND,
+    KpDblAmpersand = SDL_SCANCODE_KP_DBLAMPERSAND,
+    KpVerticalBar = SDL_SCANCODE_KP_VERTICALBAR,
+    KpDblVerticalBar = SDL_SCANCODE_KP_DBLVERTICALBAR,
+    KpColon = SDL_SCANCODE_KP_COLON,
+    KpHash = SDL_SCANCODE_KP_HASH,
+    KpSpace = SDL_SCANCODE_KP_SPACE,
+    KpAt = SDL_SCANCODE_KP_AT,
+    KpExclam = SDL_SCANCODE_KP_EXCLAM,
+    KpMemStore = SDL_SCANCODE_KP_MEMSTORE,
+    KpMemRecall = SDL_SCANCODE_KP_MEMRECALL,
+    KpMemClear = SDL_SCANCODE_KP_MEMCLEAR,
+    KpMemAdd = SDL_SCANCODE_KP_MEMADD,
+    KpMemSubtract = SDL_SCANCODE_KP_MEMSUBTRACT,
+    KpMemMultiply = SDL_SCANCODE_KP_MEMMULTIPLY,
+    KpMemDivide = SDL_SCANCODE_KP_MEMDIVIDE,
+    KpPlusMinus = SDL_SCANCODE_KP_PLUSMINUS,
+    KpClear = SDL_SCANCODE_KP_CLEAR,
+    KpClearEntry = SDL_SCANCODE_KP_CLEARENTRY,
+    KpBinary = SDL_SCANCODE_KP_BINARY,
+    KpOctal = SDL_SCANCODE_KP_OCTAL,
+    KpDecimal = SDL_SCANCODE_KP_DECIMAL,
+    KpHexadecimal = SDL_SCANCODE_KP_HEXADECIMAL,
+    LCtrl = SDL_SCANCODE_LCTRL,
+    LShift = SDL_SCANCODE_LSHIFT,
+    LAlt = SDL_SCANCODE_LALT,
+    LGui = SDL_SCANCODE_LGUI,
+    RCtrl = SDL_SCANCODE_RCTRL,
+    RShift = SDL_SCANCODE_RSHIFT,
+    RAlt = SDL_SCANCODE_RALT,
+    RGui = SDL_SCANCODE_RGUI,
+    Mode = SDL_SCANCODE_MODE,
+    AudioNext = SDL_SCANCODE_AUDIONEXT,
+    AudioPrev = SDL_SCANCODE_AUDIOPREV,
+    AudioStop = SDL_SCANCODE_AUDIOSTOP,
+    AudioPlay = SDL_SCANCODE_AUDIOPLAY,
+    AudioMute = SDL_SCANCODE_AUDIOMUTE,
+    MediaSelect = SDL_SCANCODE_MEDIASELECT,
+    Www = SDL_SCANCODE_WWW,
+    Mail = SDL_SCANCODE_MAIL,
+    Calculator = SDL_SCANCODE_CALCULATOR,
+    Computer = SDL_SCANCODE_COMPUTER,
+    AcSearch = SDL_SCANCODE_AC_SEARCH,
+    AcHome = SDL_SCANCODE_AC_HOME,
+    AcBack = SDL_SCANCODE_AC_BACK,
+    AcForward = SDL_SCANCODE_AC_FORWARD,
+    AcStop = SDL_SCANCODE_AC_STOP,
+    AcRefresh = SDL_SCANCODE_AC_REFRESH,
+    AcBookmarks = SDL_SCANCODE_AC_BOOKMARKS,
+    BrightnessDown = SDL_SCANCODE_BRIGHTNESSDOWN,
+    BrightnessUp = SDL_SCANCODE_BRIGHTNESSUP,
+    DisplaySwitch = SDL_SCANCODE_DISPLAYSWITCH,
+    KbdIllumToggle = SDL_SCANCODE_KBDILLUMTOGGLE,
+    KbdIllumDown = SDL_SCANCODE_KBDILLUMDOWN,
+    KbdIllumUp = SDL_SCANCODE_KBDILLUMUP,
+    Eject = SDL_SCANCODE_EJECT,
+    Sleep = SDL_SCANCODE_SLEEP,
+    App1 = SDL_SCANCODE_APP1,
+    App2 = SDL_SCANCODE_APP2,
+    AudioRewind = SDL_SCANCODE_AUDIOREWIND,
+    AudioFastForward = SDL_SCANCODE_AUDIOFASTFORWARD,
+  }
+}
+
+key_enum! {
+  /// A virtual key, according to the current keyboard layout.
+  ///
+  /// Use this when the meaning of the key matters, such as "press Q to quit".
+  pub enum Keycode(SDL_Keycode) {
+    Return = SDLK_RETURN,
+    Escape = SDLK_ESCAPE,
+    Backspace = SDLK_BACKSPACE,
+    Tab = SDLK_TAB,
+    Space = SDLK_SPACE,
+    Exclaim = SDLK_EXCLAIM,
+    QuoteDbl = SDLK_QUOTEDBL,
+    Hash = SDLK_HASH,
+    Percent = SDLK_PERCENT,
+    Dollar = SDLK_DOLLAR,
+    Ampersand = SDLK_AMPERSAND,
+    Quote = SDLK_QUOTE,
+    LeftParen = SDLK_LEFTPAREN,
+    RightParen = SDLK_RIGHTPAREN,
+    Asterisk = SDLK_ASTERISK,
+    Plus = SDLK_PLUS,
+    Comma = SDLK_COMMA,
+    Minus = SDLK_MINUS,
+    Period = SDLK_PERIOD,
+    Slash = SDLK_SLASH,
+    Num0 = SDLK_0,
+    Num1 = SDLK_1,
+    Num2 = SDLK_2,
+    Num3 = SDLK_3,
+    Num4 = SDLK_4,
+    Num5 = SDLK_5,
+    Num6 = SDLK_6,
+    Num7 = SDLK_7,
+    Num8 = SDLK_8,
+    Num9 = SDLK_9,
+    Colon = SDLK_COLON,
+    Semicolon = SDLK_SEMICOLON,
+    Less = SDLK_LESS,
+    Equals = SDLK_EQUALS,
+    Greater = SDLK_GREATER,
+    Question = SDLK_QUESTION,
+    At = SDLK_AT,
+    LeftBracket = SDLK_LEFTBRACKET,
+    Backslash = SDLK_BACKSLASH,
+    RightBracket = SDLK_RIGHTBRACKET,
+    Caret = SDLK_CARET,
+    Underscore = SDLK_UNDERSCORE,
+    Backquote = SDLK_BACKQUOTE,
+    A = SDLK_a,
+    B = SDLK_b,
+    C = SDLK_c,
+    D = SDLK_d,
+    E = SDLK_e,
+    F = SDLK_f,
+    G = SDLK_g,
+    H = SDLK_h,
+    I = SDLK_i,
+    J = SDLK_j,
+    K = SDLK_k,
+    L = SDLK_l,
+    M = SDLK_m,
+    N = SDLK_n,
+    O = SDLK_o,
+    P = SDLK_p,
+    Q = SDLK_q,
+    R = SDLK_r,
+    S = SDLK_s,
+    T = SDLK_t,
+    U = SDLK_u,
+    V = SDLK_v,
+    W = SDLK_w,
+    X = SDLK_x,
+    Y = SDLK_y,
+    Z = SDLK_z,
+    CapsLock = SDLK_CAPSLOCK,
+    F1 = SDLK_F1,
+    F2 = SDLK_F2,
+    F3 = SDLK_F3,
+    F4 = SDLK_F4,
+    F5 = SDLK_F5,
+    F6 = SDLK_F6,
+    F7 = SDLK_F7,
+    F8 = SDLK_F8,
+    F9 = SDLK_F9,
+    F10 = SDLK_F10,
+    F11 = SDLK_F11,
+    F12 = SDLK_F12,
+    PrintScreen = SDLK_PRINTSCREEN,
+    ScrollLock = SDLK_SCROLLLOCK,
+    Pause = SDLK_PAUSE,
+    Insert = SDLK_INSERT,
+    Home = SDLK_HOME,
+    PageUp = SDLK_PAGEUP,
+    Delete = SDLK_DELETE,
+    End = SDLK_END,
+    PageDown = SDLK_PAGEDOWN,
+    Right = SDLK_RIGHT,
+    Left = SDLK_LEFT,
+    Down = SDLK_DOWN,
+    Up = SDLK_UP,
+    NumLockClear = SDLK_NUMLOCKCLEAR,
+    KpDivide = SDLK_KP_DIVIDE,
+    KpMultiply = SDLK_KP_MULTIPLY,
+    KpMinus = SDLK_KP_MINUS,
+    KpPlus = SDLK_KP_PLUS,
+    KpEnter = SDLK_KP_ENTER,
+    Kp1 = SDLK_KP_1,
+    Kp2 = SDLK_KP_2,
+    Kp3 = SDLK_KP_3,
+    Kp4 = SDLK_KP_4,
+    Kp5 = SDLK_KP_5,
+    Kp6 = SDLK_KP_6,
+    Kp7 = SDLK_KP_7,
+    Kp8 = SDLK_KP_8,
+    Kp9 = SDLK_KP_9,
+    Kp0 = SDLK_KP_0,
+    KpPeriod = SDLK_KP_PERIOD,
+    Application = SDLK_APPLICATION,
+    Power = SDLK_POWER,
+    KpEquals = SDLK_KP_EQUALS,
+    F13 = SDLK_F13,
+    F14 = SDLK_F14,
+    F15 = SDLK_F15,
+    F16 = SDLK_F16,
+    F17 = SDLK_F17,
+    F18 = SDLK_F18,
+    F19 = SDLK_F19,
+    F20 = SDLK_F20,
+    F21 = SDLK_F21,
+    F22 = SDLK_F22,
+    F23 = SDLK_F23,
+    F24 = SDLK_F24,
+    Execute = SDLK_EXECUTE,
+    Help = SDLK_HELP,
+    Menu = SDLK_MENU,
+    Select = SDLK_SELECT,
+    Stop = SDLK_STOP,
+    Again = SDLK_AGAIN,
+    Undo = SDLK_UNDO,
+    Cut = SDLK_CUT,
+    Copy = SDLK_COPY,
+    Paste = SDLK_PASTE,
+    Find = SDLK_FIND,
+    Mute = SDLK_MUTE,
+    VolumeUp = SDLK_VOLUMEUP,
+    VolumeDown = SDLK_VOLUMEDOWN,
+    KpComma = SDLK_KP_COMMA,
+    KpEqualsAs400 = SDLK_KP_EQUALSAS400,
+    AltErase = SDLK_ALTERASE,
+    SysReq = SDLK_SYSREQ,
+    Cancel = SDLK_CANCEL,
+    Clear = SDLK_CLEAR,
+    Prior = SDLK_PRIOR,
+    Return2 = SDLK_RETURN2,
+    Separator = SDLK_SEPARATOR,
+    Out = SDLK_OUT,
+    Oper = SDLK_OPER,
+    ClearAgain = SDLK_CLEARAGAIN,
+    CrSel = SDLK_CRSEL,
+    ExSel = SDLK_EXSEL,
+    Kp00 = SDLK_KP_00,
+    Kp000 = SDLK_KP_000,
+    ThousandsSeparator = SDLK_THOUSANDSSEPARATOR,
+    DecimalSeparator = SDLK_DECIMALSEPARATOR,
+    CurrencyUnit = SDLK_CURRENCYUNIT,
+    CurrencySubUnit = SDLK_CURRENCYSUBUNIT,
+    KpLeftParen = SDLK_KP_LEFTPAREN,
+    KpRightParen = SDLK_KP_RIGHTPAREN,
+    KpLeftBrace = SDLK_KP_LEFTBRACE,
+    KpRightBrace = SDLK_KP_RIGHTBRACE,
+    KpTab = SDLK_KP_TAB,
+    KpBackspace = SDLK_KP_BACKSPACE,
+    KpA = SDLK_KP_A,
+    KpB = SDLK_KP_B,
+    KpC = SDLK_KP_C,
+    KpD = SDLK_KP_D,
+    KpE = SDLK_KP_E,
+    KpF = SDLK_KP_F,
+    KpXor = SDLK_KP_XOR,
+    KpPower = SDLK_KP_POWER,
+    KpPercent = SDLK_KP_PERCENT,
+    KpLess = SDLK_KP_LESS,
+    KpGreater = SDLK_KP_GREATER,
+    KpAmpersand = SDLK_KP_AMPERSAND,
+    KpDblAmpersand = SDLK_KP_DBLAMPERSAND,
+    KpVerticalBar = SDLK_KP_VERTICALBAR,
+    KpDblVerticalBar = SDLK_KP_DBLVERTICALBAR,
+    KpColon = SDLK_KP_COLON,
+    KpHash = SDLK_KP_HASH,
+    KpSpace = SDLK_KP_SPACE,
+    KpAt = SDLK_KP_AT,
+    KpExclam = SDLK_KP_EXCLAM,
+    KpMemStore = SDLK_KP_MEMSTORE,
+    KpMemRecall = SDLK_KP_MEMRECALL,
+    KpMemClear = SDLK_KP_MEMCLEAR,
+    KpMemAdd = SDLK_KP_MEMADD,
+    KpMemSubtract = SDLK_KP_MEMSUBTRACT,
+    KpMemMultiply = SDLK_KP_MEMMULTIPLY,
+    KpMemDivide = SDLK_KP_MEMDIVIDE,
+    KpPlusMinus = SDLK_KP_PLUSMINUS,
+    KpClear = SDLK_KP_CLEAR,
+    KpClearEntry = SDLK_KP_CLEARENTRY,
+    KpBinary = SDLK_KP_BINARY,
+    KpOctal = SDLK_KP_OCTAL,
+    KpDecimal = SDLK_KP_DECIMAL,
+    KpHexadecimal = SDLK_KP_HEXADECIMAL,
+    LCtrl = SDLK_LCTRL,
+    LShift = SDLK_LSHIFT,
+    LAlt = SDLK_LALT,
+    LGui = SDLK_LGUI,
+    RCtrl = SDLK_RCTRL,
+    RShift = SDLK_RSHIFT,
+    RAlt = SDLK_RALT,
+    RGui = SDLK_RGUI,
+    Mode = SDLK_MODE,
+    AudioNext = SDLK_AUDIONEXT,
+    AudioPrev = SDLK_AUDIOPREV,
+    AudioStop = SDLK_AUDIOSTOP,
+    AudioPlay = SDLK_AUDIOPLAY,
+    AudioMute = SDLK_AUDIOMUTE,
+    MediaSelect = SDLK_MEDIASELECT,
+    Www = SDLK_WWW,
+    Mail = SDLK_MAIL,
+    Calculator = SDLK_CALCULATOR,
+    Computer = SDLK_COMPUTER,
+    AcSearch = SDLK_AC_SEARCH,
+    AcHome = SDLK_AC_HOME,
+    AcBack = SDLK_AC_BACK,
+    AcForward = SDLK_AC_FORWARD,
+    AcStop = SDLK_AC_STOP,
+    AcRefresh = SDLK_AC_REFRESH,
+    AcBookmarks = SDLK_AC_BOOKMARKS,
+    BrightnessDown = SDLK_BRIGHTNESSDOWN,
+    BrightnessUp = SDLK_BRIGHTNESSUP,
+    DisplaySwitch = SDLK_DISPLAYSWITCH,
+    KbdIllumToggle = SDLK_KBDILLUMTOGGLE,
+    KbdIllumDown = SDLK_KBDILLUMDOWN,
+    KbdIllumUp = SDLK_KBDILLUMUP,
+    Eject = SDLK_EJECT,
+    Sleep = SDLK_SLEEP,
+    App1 = SDLK_APP1,
+    App2 = SDLK_APP2,
+    AudioRewind = SDLK_AUDIOREWIND,
+    AudioFastForward = SDLK_AUDIOFASTFORWARD,
+  }
+}
+
+/// The modifier keys held during a key event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct KeyMods(SDL_Keymod);
+impl KeyMods {
+  pub const NONE: Self = Self(KMOD_NONE);
+  pub const LSHIFT: Self = Self(KMOD_LSHIFT);
+  pub const RSHIFT: Self = Self(KMOD_RSHIFT);
+  pub const LCTRL: Self = Self(KMOD_LCTRL);
+  pub const RCTRL: Self = Self(KMOD_RCTRL);
+  pub const LALT: Self = Self(KMOD_LALT);
+  pub const RALT: Self = Self(KMOD_RALT);
+  pub const LGUI: Self = Self(KMOD_LGUI);
+  pub const RGUI: Self = Self(KMOD_RGUI);
+  pub const NUM: Self = Self(KMOD_NUM);
+  pub const CAPS: Self = Self(KMOD_CAPS);
+  pub const MODE: Self = Self(KMOD_MODE);
+  pub const SHIFT: Self = Self(KMOD_SHIFT);
+  pub const CTRL: Self = Self(KMOD_CTRL);
+  pub const ALT: Self = Self(KMOD_ALT);
+  pub const GUI: Self = Self(KMOD_GUI);
+
+  /// If *any* of the bits in `other` are also set in `self`.
+  #[inline]
+  #[must_use]
+  pub const fn intersects(self, other: Self) -> bool {
+    (self.0 .0 & other.0 .0) != 0
+  }
+
+  /// If *all* of the bits in `other` are also set in `self`.
+  #[inline]
+  #[must_use]
+  pub const fn contains(self, other: Self) -> bool {
+    (self.0 .0 & other.0 .0) == other.0 .0
+  }
+
+  /// Either shift key.
+  #[inline]
+  #[must_use]
+  pub const fn shift(self) -> bool {
+    self.intersects(Self::SHIFT)
+  }
+
+  /// Either ctrl key.
+  #[inline]
+  #[must_use]
+  pub const fn ctrl(self) -> bool {
+    self.intersects(Self::CTRL)
+  }
+
+  /// Either alt key.
+  #[inline]
+  #[must_use]
+  pub const fn alt(self) -> bool {
+    self.intersects(Self::ALT)
+  }
+
+  /// Either "GUI" key (Windows key, Command key, etc).
+  #[inline]
+  #[must_use]
+  pub const fn gui(self) -> bool {
+    self.intersects(Self::GUI)
+  }
+
+  /// Caps Lock is active.
+  #[inline]
+  #[must_use]
+  pub const fn caps_lock(self) -> bool {
+    self.intersects(Self::CAPS)
+  }
+
+  /// Num Lock is active.
+  #[inline]
+  #[must_use]
+  pub const fn num_lock(self) -> bool {
+    self.intersects(Self::NUM)
+  }
+}
+impl From<SDL_Keymod> for KeyMods {
+  #[inline]
+  fn from(value: SDL_Keymod) -> Self {
+    Self(value)
+  }
+}
+impl From<KeyMods> for SDL_Keymod {
+  #[inline]
+  fn from(value: KeyMods) -> Self {
+    value.0
+  }
+}
+impl core::ops::BitOr for KeyMods {
+  type Output = Self;
+  #[inline]
+  fn bitor(self, rhs: Self) -> Self::Output {
+    Self(self.0 | rhs.0)
+  }
+}
+impl core::ops::BitOrAssign for KeyMods {
+  #[inline]
+  fn bitor_assign(&mut self, rhs: Self) {
+    *self = *self | rhs;
+  }
+}
+
+/// A view of which keys are currently held down.
+///
+/// This is updated by SDL as events are processed, so reading it after a call
+/// to [`poll_events`](Sdl::poll_events) will show the newer state.
+pub struct KeyboardState<'s> {
+  keys: *const u8,
+  len: usize,
+  _sdl: PhantomData<&'s Sdl>,
+}
+impl KeyboardState<'_> {
+  /// If the key at the given position is held down.
+  #[inline]
+  #[must_use]
+  pub fn is_pressed(&self, scancode: Scancode) -> bool {
+    let i = SDL_Scancode::from(scancode).0;
+    match usize::try_from(i) {
+      Ok(i) if i < self.len => unsafe { self.keys.add(i).read() != 0 },
+      _ => false,
+    }
+  }
+
+  /// All of the keys that are currently held down.
+  #[inline]
+  pub fn pressed_scancodes(&self) -> impl Iterator<Item = Scancode> + '_ {
+    (0..self.len)
+      .filter(|i| unsafe { self.keys.add(*i).read() != 0 })
+      .map(|i| Scancode::from(SDL_Scancode(i as i32)))
+  }
+}
+impl Index<Scancode> for KeyboardState<'_> {
+  type Output = bool;
+  #[inline]
+  fn index(&self, scancode: Scancode) -> &Self::Output {
+    if self.is_pressed(scancode) {
+      &true
+    } else {
+      &false
+    }
+  }
+}
+
+impl Sdl {
+  /// Gets a view of the current keyboard state, indexable by [`Scancode`].
+  #[inline]
+  pub fn keyboard_state(&self) -> KeyboardState<'_> {
+    let mut len: c_int = 0;
+    let keys = unsafe { SDL_GetKeyboardState(&mut len) };
+    KeyboardState { keys, len: len.try_into().unwrap_or(0), _sdl: PhantomData }
+  }
+
+  /// The current modifier key state.
+  #[inline]
+  pub fn get_mod_state(&self) -> KeyMods {
+    KeyMods::from(unsafe { SDL_GetModState() })
+  }
+
+  /// Overrides SDL's idea of which modifier keys are active.
+  ///
+  /// This doesn't change the actual keyboard.
+  #[inline]
+  pub fn set_mod_state(&self, mods: KeyMods) {
+    unsafe { SDL_SetModState(mods.into()) }
+  }
+
+  /// The key that a physical key position maps to in the current layout.
+  #[inline]
+  pub fn keycode_from_scancode(&self, scancode: Scancode) -> Keycode {
+    Keycode::from(unsafe { SDL_GetKeyFromScancode(scancode.into()) })
+  }
+
+  /// The physical key position of a key in the current layout.
+  #[inline]
+  pub fn scancode_from_keycode(&self, keycode: Keycode) -> Scancode {
+    Scancode::from(unsafe { SDL_GetScancodeFromKey(keycode.into()) })
+  }
+
+  /// A human readable name for the key, suitable for showing to the user.
+  ///
+  /// Keys without a name give an empty string.
+  #[inline]
+  pub fn key_name(&self, keycode: Keycode) -> String {
+    unsafe { string_from_sdl(SDL_GetKeyName(keycode.into())) }
+  }
+
+  /// A name for the physical key position.
+  ///
+  /// These names are not localized, and keys without a name give an empty
+  /// string.
+  #[inline]
+  pub fn scancode_name(&self, scancode: Scancode) -> String {
+    unsafe { string_from_sdl(SDL_GetScancodeName(scancode.into())) }
+  }
+
+  /// The reverse of [`key_name`](Self::key_name).
+  #[inline]
+  pub fn keycode_from_name(&self, name: &str) -> Keycode {
+    let name_null = alloc::format!("{name}\0");
+    Keycode::from(unsafe { SDL_GetKeyFromName(name_null.as_ptr().cast()) })
+  }
+
+  /// The reverse of [`scancode_name`](Self::scancode_name).
+  #[inline]
+  pub fn scancode_from_name(&self, name: &str) -> Scancode {
+    let name_null = alloc::format!("{name}\0");
+    Scancode::from(unsafe { SDL_GetScancodeFromName(name_null.as_ptr().cast()) })
+  }
+}
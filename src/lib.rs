@@ -3,15 +3,19 @@
 
 extern crate alloc;
 
-use alloc::sync::Arc;
-use fermium::{mouse::SDL_SetRelativeMouseMode, prelude::SDL_SetHint};
+use alloc::{string::String, sync::Arc, vec::Vec};
+use fermium::{c_char, prelude::SDL_SetHint};
 use init::{InitFlags, SdlInit};
 
 pub mod controller;
 pub mod error;
 pub mod events;
 pub mod init;
+pub mod keyboard;
+pub mod mouse;
+mod rwops;
 pub mod surface;
+pub mod touch;
 pub mod video;
 
 #[derive(Clone)]
@@ -35,21 +39,43 @@ impl Sdl {
     }
     .into()
   }
+}
 
-  /// Tries to set the mouse into relative mode, returning `err` if that isn't
-  /// supported.
-  ///
-  /// While the mouse is in relative mode, the cursor is hidden, and the driver
-  /// will try to report continuous motion in the current window. Only relative
-  /// motion events will be delivered, the mouse position will not change.
-  ///
-  /// In other words, this is what you'd use for an "FPS" style interface.
-  #[inline]
-  pub fn set_relative_mouse_mode(&self, b: bool) -> Result<(), ()> {
-    if unsafe { SDL_SetRelativeMouseMode(b.into()) } == 0 {
-      Ok(())
-    } else {
-      Err(())
-    }
+/// Copies a null terminated string from SDL into a `String`.
+///
+/// Null pointers give an empty string, and invalid utf8 is replaced lossily.
+///
+/// ## Safety
+/// * The pointer must be null, or point to a null terminated string.
+pub(crate) unsafe fn string_from_sdl(mut p: *const c_char) -> String {
+  if p.is_null() {
+    return String::new();
+  }
+  let mut vec: Vec<u8> = Vec::new();
+  while *p != 0 {
+    vec.push(*p as u8);
+    p = p.add(1);
+  }
+  match String::from_utf8(vec) {
+    Ok(s) => s,
+    Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+  }
+}
+
+/// If the SDL library that's linked at runtime is at least the version given.
+///
+/// Newer SDL versions add fields to some structs, and older versions leave
+/// those fields uninitialized, so check this before reading such a field.
+pub(crate) fn linked_sdl_is_at_least(major: u8, minor: u8, patch: u8) -> bool {
+  use core::sync::atomic::{AtomicU32, Ordering};
+  // 0 means "not yet checked", since SDL 0.0.0 doesn't exist.
+  static LINKED: AtomicU32 = AtomicU32::new(0);
+  let mut packed = LINKED.load(Ordering::Relaxed);
+  if packed == 0 {
+    let mut v = fermium::prelude::SDL_version { major: 0, minor: 0, patch: 0 };
+    unsafe { fermium::prelude::SDL_GetVersion(&mut v) };
+    packed = u32::from_be_bytes([0, v.major, v.minor, v.patch]);
+    LINKED.store(packed, Ordering::Relaxed);
   }
+  packed >= u32::from_be_bytes([0, major, minor, patch])
 }
@@ -0,0 +1,330 @@
+use alloc::sync::Arc;
+use core::ptr::NonNull;
+use fermium::prelude::*;
+
+use crate::{
+  error::{get_error, SdlError},
+  init::SdlInit,
+  surface::Surface,
+  video::CommonWindow,
+  Sdl,
+};
+
+/// A single mouse button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MouseButton {
+  Left,
+  Middle,
+  Right,
+  X1,
+  X2,
+  /// Any other button index.
+  Other(u8),
+}
+impl From<u8> for MouseButton {
+  #[inline]
+  fn from(value: u8) -> Self {
+    match u32::from(value) {
+      SDL_BUTTON_LEFT => Self::Left,
+      SDL_BUTTON_MIDDLE => Self::Middle,
+      SDL_BUTTON_RIGHT => Self::Right,
+      SDL_BUTTON_X1 => Self::X1,
+      SDL_BUTTON_X2 => Self::X2,
+      _ => Self::Other(value),
+    }
+  }
+}
+
+/// A set of mouse buttons.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct MouseButtons(u32);
+impl MouseButtons {
+  pub const NONE: Self = Self(0);
+  pub const LEFT: Self = Self(SDL_BUTTON_LMASK);
+  pub const MIDDLE: Self = Self(SDL_BUTTON_MMASK);
+  pub const RIGHT: Self = Self(SDL_BUTTON_RMASK);
+  pub const X1: Self = Self(SDL_BUTTON_X1MASK);
+  pub const X2: Self = Self(SDL_BUTTON_X2MASK);
+
+  /// If *all* of the buttons in `other` are also in `self`.
+  #[inline]
+  #[must_use]
+  pub const fn contains(self, other: Self) -> bool {
+    (self.0 & other.0) == other.0
+  }
+
+  #[inline]
+  #[must_use]
+  pub const fn left(self) -> bool {
+    self.contains(Self::LEFT)
+  }
+
+  #[inline]
+  #[must_use]
+  pub const fn middle(self) -> bool {
+    self.contains(Self::MIDDLE)
+  }
+
+  #[inline]
+  #[must_use]
+  pub const fn right(self) -> bool {
+    self.contains(Self::RIGHT)
+  }
+
+  #[inline]
+  #[must_use]
+  pub const fn x1(self) -> bool {
+    self.contains(Self::X1)
+  }
+
+  #[inline]
+  #[must_use]
+  pub const fn x2(self) -> bool {
+    self.contains(Self::X2)
+  }
+
+  /// The raw bits, with bit `N-1` set when button `N` is held.
+  #[inline]
+  #[must_use]
+  pub const fn bits(self) -> u32 {
+    self.0
+  }
+}
+impl From<u32> for MouseButtons {
+  #[inline]
+  fn from(value: u32) -> Self {
+    Self(value)
+  }
+}
+impl core::ops::BitOr for MouseButtons {
+  type Output = Self;
+  #[inline]
+  fn bitor(self, rhs: Self) -> Self::Output {
+    Self(self.0 | rhs.0)
+  }
+}
+impl core::ops::BitOrAssign for MouseButtons {
+  #[inline]
+  fn bitor_assign(&mut self, rhs: Self) {
+    *self = *self | rhs;
+  }
+}
+
+impl Sdl {
+  /// Tries to set the mouse into relative mode, returning `err` if that isn't
+  /// supported.
+  ///
+  /// While the mouse is in relative mode, the cursor is hidden, and the driver
+  /// will try to report continuous motion in the current window. Only relative
+  /// motion events will be delivered, the mouse position will not change.
+  ///
+  /// In other words, this is what you'd use for an "FPS" style interface.
+  #[inline]
+  pub fn set_relative_mouse_mode(&self, b: bool) -> Result<(), ()> {
+    if unsafe { SDL_SetRelativeMouseMode(b.into()) } == 0 {
+      Ok(())
+    } else {
+      Err(())
+    }
+  }
+
+  /// If the mouse is currently in relative mode.
+  #[inline]
+  pub fn get_relative_mouse_mode(&self) -> bool {
+    unsafe { SDL_GetRelativeMouseMode() }.into()
+  }
+
+  /// Gets the held buttons and the position of the mouse relative to the window
+  /// with mouse focus.
+  #[inline]
+  pub fn get_mouse_state(&self) -> (MouseButtons, i32, i32) {
+    let mut x = 0;
+    let mut y = 0;
+    let buttons = unsafe { SDL_GetMouseState(&mut x, &mut y) };
+    (MouseButtons(buttons), x, y)
+  }
+
+  /// Gets the held buttons and the position of the mouse on the desktop.
+  ///
+  /// Unlike [`get_mouse_state`](Self::get_mouse_state), this asks the OS
+  /// directly, so it works even when the mouse is outside of our windows.
+  #[inline]
+  pub fn get_global_mouse_state(&self) -> (MouseButtons, i32, i32) {
+    let mut x = 0;
+    let mut y = 0;
+    let buttons = unsafe { SDL_GetGlobalMouseState(&mut x, &mut y) };
+    (MouseButtons(buttons), x, y)
+  }
+
+  /// Gets the held buttons and the mouse motion since the last call to this
+  /// method.
+  #[inline]
+  pub fn get_relative_mouse_state(&self) -> (MouseButtons, i32, i32) {
+    let mut x = 0;
+    let mut y = 0;
+    let buttons = unsafe { SDL_GetRelativeMouseState(&mut x, &mut y) };
+    (MouseButtons(buttons), x, y)
+  }
+
+  /// Moves the mouse to the given position within the window.
+  ///
+  /// This generates a mouse motion event.
+  #[inline]
+  pub fn warp_mouse_in_window(&self, win: &CommonWindow, x: i32, y: i32) {
+    unsafe { SDL_WarpMouseInWindow(win.win.as_ptr(), x, y) }
+  }
+
+  /// Moves the mouse to the given position on the desktop.
+  #[inline]
+  pub fn warp_mouse_global(&self, x: i32, y: i32) -> Result<(), SdlError> {
+    if unsafe { SDL_WarpMouseGlobal(x, y) } == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Captures the mouse, so that mouse events are tracked even outside of the
+  /// window.
+  ///
+  /// This is intended for short term use, such as while dragging with a button
+  /// held.
+  #[inline]
+  pub fn capture_mouse(&self, capture: bool) -> Result<(), SdlError> {
+    if unsafe { SDL_CaptureMouse(capture.into()) } == 0 {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+}
+
+/// The cursors that the system provides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(i32)]
+pub enum SystemCursor {
+  Arrow = SDL_SYSTEM_CURSOR_ARROW.0,
+  IBeam = SDL_SYSTEM_CURSOR_IBEAM.0,
+  Wait = SDL_SYSTEM_CURSOR_WAIT.0,
+  Crosshair = SDL_SYSTEM_CURSOR_CROSSHAIR.0,
+  /// Small wait cursor (or [`Wait`](Self::Wait) if not available).
+  WaitArrow = SDL_SYSTEM_CURSOR_WAITARROW.0,
+  /// Double arrow pointing northwest and southeast.
+  SizeNWSE = SDL_SYSTEM_CURSOR_SIZENWSE.0,
+  /// Double arrow pointing northeast and southwest.
+  SizeNESW = SDL_SYSTEM_CURSOR_SIZENESW.0,
+  /// Double arrow pointing west and east.
+  SizeWE = SDL_SYSTEM_CURSOR_SIZEWE.0,
+  /// Double arrow pointing north and south.
+  SizeNS = SDL_SYSTEM_CURSOR_SIZENS.0,
+  /// Four pointed arrow pointing north, south, east, and west.
+  SizeAll = SDL_SYSTEM_CURSOR_SIZEALL.0,
+  /// Slashed circle or crossbones.
+  No = SDL_SYSTEM_CURSOR_NO.0,
+  Hand = SDL_SYSTEM_CURSOR_HAND.0,
+}
+
+/// A mouse cursor image.
+///
+/// Use [`set_cursor`](Sdl::set_cursor) to make it the active cursor. If the
+/// active cursor is dropped then SDL goes back to the default cursor.
+pub struct Cursor {
+  cur: NonNull<SDL_Cursor>,
+  #[allow(dead_code)]
+  init: Arc<SdlInit>,
+}
+impl Drop for Cursor {
+  #[inline]
+  fn drop(&mut self) {
+    unsafe { SDL_FreeCursor(self.cur.as_ptr()) }
+  }
+}
+
+impl Sdl {
+  #[inline]
+  pub fn create_system_cursor(&self, id: SystemCursor) -> Result<Cursor, SdlError> {
+    let p = unsafe { SDL_CreateSystemCursor(SDL_SystemCursor(id as i32)) };
+    match NonNull::new(p) {
+      Some(cur) => Ok(Cursor { cur, init: self.init.clone() }),
+      None => Err(get_error()),
+    }
+  }
+
+  /// Makes a cursor from a color image.
+  ///
+  /// * `hot_x` and `hot_y` are the "click point" of the cursor within the image.
+  #[inline]
+  pub fn create_color_cursor(
+    &self, surface: &Surface, hot_x: i32, hot_y: i32,
+  ) -> Result<Cursor, SdlError> {
+    let p = unsafe { SDL_CreateColorCursor(surface.surf.as_ptr(), hot_x, hot_y) };
+    match NonNull::new(p) {
+      Some(cur) => Ok(Cursor { cur, init: self.init.clone() }),
+      None => Err(get_error()),
+    }
+  }
+
+  /// Makes a cursor from monochrome bitmap data.
+  ///
+  /// Each bit of `data` and `mask` is one pixel, with the most significant bit
+  /// of each byte being the leftmost pixel. For each pixel:
+  ///
+  /// | data | mask | result |
+  /// |:-:|:-:|:-|
+  /// | 0 | 1 | White |
+  /// | 1 | 1 | Black |
+  /// | 0 | 0 | Transparent |
+  /// | 1 | 0 | Inverted color if possible, black if not. |
+  ///
+  /// * `width` must be a multiple of 8.
+  /// * `data` and `mask` must each be `width / 8 * height` bytes.
+  /// * `hot_x` and `hot_y` are the "click point" of the cursor within the image.
+  #[inline]
+  pub fn create_cursor(
+    &self, data: &[u8], mask: &[u8], width: i32, height: i32, hot_x: i32, hot_y: i32,
+  ) -> Result<Cursor, SdlError> {
+    if width <= 0
+      || height <= 0
+      || width % 8 != 0
+      || (width / 8).checked_mul(height).unwrap_or_default() as usize != data.len()
+      || data.len() != mask.len()
+    {
+      return Err(SdlError::new("illegal input dimensions"));
+    }
+    let p = unsafe { SDL_CreateCursor(data.as_ptr(), mask.as_ptr(), width, height, hot_x, hot_y) };
+    match NonNull::new(p) {
+      Some(cur) => Ok(Cursor { cur, init: self.init.clone() }),
+      None => Err(get_error()),
+    }
+  }
+
+  /// Sets the active cursor.
+  #[inline]
+  pub fn set_cursor(&self, cursor: &Cursor) {
+    unsafe { SDL_SetCursor(cursor.cur.as_ptr()) }
+  }
+
+  /// Goes back to the system's default cursor.
+  #[inline]
+  pub fn set_default_cursor(&self) {
+    unsafe { SDL_SetCursor(SDL_GetDefaultCursor()) }
+  }
+
+  /// Shows or hides the cursor.
+  #[inline]
+  pub fn show_cursor(&self, show: bool) -> Result<(), SdlError> {
+    let toggle = if show { SDL_ENABLE } else { SDL_DISABLE };
+    if unsafe { SDL_ShowCursor(toggle) } < 0 {
+      Err(get_error())
+    } else {
+      Ok(())
+    }
+  }
+
+  /// If the cursor is currently shown.
+  #[inline]
+  pub fn is_cursor_shown(&self) -> bool {
+    SDL_ENABLE == unsafe { SDL_ShowCursor(SDL_QUERY) }
+  }
+}
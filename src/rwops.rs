@@ -0,0 +1,151 @@
+//! Lets SDL read from and write to Rust IO types.
+
+use core::ptr::NonNull;
+use fermium::prelude::*;
+use std::io::{Read, Write};
+
+use crate::error::{get_error, SdlError};
+
+extern "C" {
+  fn SDL_AllocRW() -> *mut SDL_RWops;
+}
+
+/// The leading portion of an `SDL_RWops`.
+///
+/// `fermium` keeps the struct opaque, so we declare the fields we need. The
+/// full struct is always allocated by SDL with `SDL_AllocRW`, we only ever fill
+/// in the callbacks, the type, and the first `hidden.unknown` data pointer.
+#[repr(C)]
+struct RWopsHead {
+  size: Option<unsafe extern "C" fn(*mut RWopsHead) -> i64>,
+  seek: Option<unsafe extern "C" fn(*mut RWopsHead, i64, c_int) -> i64>,
+  read: Option<unsafe extern "C" fn(*mut RWopsHead, *mut c_void, usize, usize) -> usize>,
+  write: Option<unsafe extern "C" fn(*mut RWopsHead, *const c_void, usize, usize) -> usize>,
+  close: Option<unsafe extern "C" fn(*mut RWopsHead) -> c_int>,
+  type_: u32,
+  data1: *mut c_void,
+}
+
+/// `SDL_RWOPS_UNKNOWN`
+const RWOPS_UNKNOWN: u32 = 0;
+
+unsafe extern "C" fn no_size(_: *mut RWopsHead) -> i64 {
+  -1
+}
+unsafe extern "C" fn no_seek(_: *mut RWopsHead, _: i64, _: c_int) -> i64 {
+  -1
+}
+unsafe extern "C" fn no_read(_: *mut RWopsHead, _: *mut c_void, _: usize, _: usize) -> usize {
+  0
+}
+unsafe extern "C" fn no_write(_: *mut RWopsHead, _: *const c_void, _: usize, _: usize) -> usize {
+  0
+}
+unsafe extern "C" fn no_close(_: *mut RWopsHead) -> c_int {
+  0
+}
+
+struct ReadCtx<'r> {
+  reader: &'r mut dyn Read,
+  error: Option<std::io::Error>,
+}
+struct WriteCtx<'w> {
+  writer: &'w mut dyn Write,
+  error: Option<std::io::Error>,
+}
+
+unsafe extern "C" fn read_cb(
+  ctx: *mut RWopsHead, ptr: *mut c_void, size: usize, maxnum: usize,
+) -> usize {
+  let ctx = &mut *(*ctx).data1.cast::<ReadCtx<'_>>();
+  if size == 0 || ctx.error.is_some() {
+    return 0;
+  }
+  let buf = core::slice::from_raw_parts_mut(ptr.cast::<u8>(), size.saturating_mul(maxnum));
+  let mut filled = 0;
+  while filled < buf.len() {
+    match ctx.reader.read(&mut buf[filled..]) {
+      Ok(0) => break,
+      Ok(n) => filled += n,
+      Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+      Err(e) => {
+        ctx.error = Some(e);
+        break;
+      }
+    }
+  }
+  filled / size
+}
+
+unsafe extern "C" fn write_cb(
+  ctx: *mut RWopsHead, ptr: *const c_void, size: usize, num: usize,
+) -> usize {
+  let ctx = &mut *(*ctx).data1.cast::<WriteCtx<'_>>();
+  if ctx.error.is_some() {
+    return 0;
+  }
+  let buf = core::slice::from_raw_parts(ptr.cast::<u8>(), size.saturating_mul(num));
+  match ctx.writer.write_all(buf) {
+    Ok(()) => num,
+    Err(e) => {
+      ctx.error = Some(e);
+      0
+    }
+  }
+}
+
+/// Allocates an `SDL_RWops`, fills in the callbacks, runs the operation, and
+/// then frees the `SDL_RWops`.
+fn with_rwops<T>(
+  read: Option<unsafe extern "C" fn(*mut RWopsHead, *mut c_void, usize, usize) -> usize>,
+  write: Option<unsafe extern "C" fn(*mut RWopsHead, *const c_void, usize, usize) -> usize>,
+  data1: *mut c_void, op: impl FnOnce(*mut SDL_RWops) -> T,
+) -> Result<T, SdlError> {
+  let rw = match NonNull::new(unsafe { SDL_AllocRW() }) {
+    Some(rw) => rw,
+    None => return Err(get_error()),
+  };
+  unsafe {
+    let head = rw.as_ptr().cast::<RWopsHead>();
+    (*head).size = Some(no_size);
+    (*head).seek = Some(no_seek);
+    (*head).read = Some(read.unwrap_or(no_read));
+    (*head).write = Some(write.unwrap_or(no_write));
+    (*head).close = Some(no_close);
+    (*head).type_ = RWOPS_UNKNOWN;
+    (*head).data1 = data1;
+  }
+  let out = op(rw.as_ptr());
+  unsafe { SDL_FreeRW(rw.as_ptr()) };
+  Ok(out)
+}
+
+/// Runs an operation with an `SDL_RWops` that reads from the reader given.
+///
+/// Any IO error from the reader is returned in place of the operation's
+/// output.
+pub(crate) fn with_read_rwops<T>(
+  reader: &mut dyn Read, op: impl FnOnce(*mut SDL_RWops) -> T,
+) -> Result<T, SdlError> {
+  let mut ctx = ReadCtx { reader, error: None };
+  let out = with_rwops(Some(read_cb), None, (&mut ctx as *mut ReadCtx<'_>).cast(), op)?;
+  match ctx.error {
+    Some(e) => Err(SdlError::new(&e.to_string())),
+    None => Ok(out),
+  }
+}
+
+/// Runs an operation with an `SDL_RWops` that writes to the writer given.
+///
+/// Any IO error from the writer is returned in place of the operation's
+/// output.
+pub(crate) fn with_write_rwops<T>(
+  writer: &mut dyn Write, op: impl FnOnce(*mut SDL_RWops) -> T,
+) -> Result<T, SdlError> {
+  let mut ctx = WriteCtx { writer, error: None };
+  let out = with_rwops(None, Some(write_cb), (&mut ctx as *mut WriteCtx<'_>).cast(), op)?;
+  match ctx.error {
+    Some(e) => Err(SdlError::new(&e.to_string())),
+    None => Ok(out),
+  }
+}
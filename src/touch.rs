@@ -0,0 +1,151 @@
+use fermium::prelude::*;
+use std::io::{Read, Write};
+
+use crate::{
+  error::{get_error, SdlError},
+  rwops::{with_read_rwops, with_write_rwops},
+  Sdl,
+};
+
+extern "C" {
+  // Note: `fermium` declares this without a return type, so we declare our own
+  // (correct) version here.
+  #[allow(clashing_extern_declarations)]
+  fn SDL_GetNumTouchDevices() -> c_int;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(i32)]
+pub enum TouchDeviceType {
+  Invalid = SDL_TOUCH_DEVICE_INVALID.0,
+  /// Touch screen with window-relative coordinates.
+  Direct = SDL_TOUCH_DEVICE_DIRECT.0,
+  /// Trackpad with absolute device coordinates.
+  IndirectAbsolute = SDL_TOUCH_DEVICE_INDIRECT_ABSOLUTE.0,
+  /// Trackpad with screen cursor-relative coordinates.
+  IndirectRelative = SDL_TOUCH_DEVICE_INDIRECT_RELATIVE.0,
+}
+impl From<SDL_TouchDeviceType> for TouchDeviceType {
+  #[inline]
+  fn from(value: SDL_TouchDeviceType) -> Self {
+    match value {
+      SDL_TOUCH_DEVICE_DIRECT => Self::Direct,
+      SDL_TOUCH_DEVICE_INDIRECT_ABSOLUTE => Self::IndirectAbsolute,
+      SDL_TOUCH_DEVICE_INDIRECT_RELATIVE => Self::IndirectRelative,
+      _ => Self::Invalid,
+    }
+  }
+}
+
+/// The state of one finger on a touch device.
+///
+/// * `x`, `y`, and `pressure` are normalized to `0.0 ..= 1.0`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Finger {
+  pub id: i64,
+  pub x: f32,
+  pub y: f32,
+  pub pressure: f32,
+}
+
+impl Sdl {
+  /// The number of touch devices that SDL knows about.
+  #[inline]
+  pub fn num_touch_devices(&self) -> i32 {
+    unsafe { SDL_GetNumTouchDevices() }
+  }
+
+  /// Gets the touch id of the device at the index given.
+  #[inline]
+  pub fn get_touch_device(&self, index: i32) -> Result<i64, SdlError> {
+    let touch_id = unsafe { SDL_GetTouchDevice(index) };
+    if touch_id.0 == 0 {
+      Err(get_error())
+    } else {
+      Ok(touch_id.0)
+    }
+  }
+
+  #[inline]
+  pub fn touch_device_type(&self, touch_id: i64) -> TouchDeviceType {
+    TouchDeviceType::from(unsafe { SDL_GetTouchDeviceType(SDL_TouchID(touch_id)) })
+  }
+
+  /// The number of fingers currently on the touch device given.
+  #[inline]
+  pub fn num_touch_fingers(&self, touch_id: i64) -> i32 {
+    unsafe { SDL_GetNumTouchFingers(SDL_TouchID(touch_id)) }
+  }
+
+  /// Gets the state of a finger currently on the touch device.
+  ///
+  /// The `index` should be less than [`num_touch_fingers`](Self::num_touch_fingers).
+  #[inline]
+  pub fn get_touch_finger(&self, touch_id: i64, index: i32) -> Option<Finger> {
+    let p = unsafe { SDL_GetTouchFinger(SDL_TouchID(touch_id), index) };
+    if p.is_null() {
+      None
+    } else {
+      let f = unsafe { *p };
+      Some(Finger { id: f.id.0, x: f.x, y: f.y, pressure: f.pressure })
+    }
+  }
+}
+
+impl Sdl {
+  /// Begins recording a "dollar" gesture template on the touch device given.
+  ///
+  /// Pass `-1` to record on all touch devices. Once the user performs the
+  /// gesture you'll get a [`DollarRecord`](crate::events::Event::DollarRecord)
+  /// event with the new gesture's id, and after that a
+  /// [`DollarGesture`](crate::events::Event::DollarGesture) event whenever the
+  /// gesture is performed again.
+  #[inline]
+  pub fn record_gesture(&self, touch_id: i64) -> Result<(), SdlError> {
+    if unsafe { SDL_RecordGesture(SDL_TouchID(touch_id)) } == 0 {
+      Err(SdlError::new("beryllium: no such touch device."))
+    } else {
+      Ok(())
+    }
+  }
+
+  /// Writes all currently loaded gesture templates to the writer.
+  ///
+  /// Returns the number of templates written.
+  #[inline]
+  pub fn save_all_dollar_templates<W: Write>(&self, writer: &mut W) -> Result<i32, SdlError> {
+    with_write_rwops(writer, |rw| unsafe { SDL_SaveAllDollarTemplates(rw) })
+  }
+
+  /// Writes a single gesture template to the writer.
+  #[inline]
+  pub fn save_dollar_template<W: Write>(
+    &self, gesture_id: i64, writer: &mut W,
+  ) -> Result<(), SdlError> {
+    let saved = with_write_rwops(writer, |rw| unsafe {
+      SDL_SaveDollarTemplate(SDL_GestureID(gesture_id), rw)
+    })?;
+    if saved <= 0 {
+      Err(get_error())
+    } else {
+      Ok(())
+    }
+  }
+
+  /// Loads gesture templates from the reader onto the touch device given.
+  ///
+  /// Pass `-1` to load the templates onto all touch devices. Returns the number
+  /// of templates loaded.
+  #[inline]
+  pub fn load_dollar_templates<R: Read>(
+    &self, touch_id: i64, reader: &mut R,
+  ) -> Result<i32, SdlError> {
+    let loaded =
+      with_read_rwops(reader, |rw| unsafe { SDL_LoadDollarTemplates(SDL_TouchID(touch_id), rw) })?;
+    if loaded < 0 {
+      Err(get_error())
+    } else {
+      Ok(loaded)
+    }
+  }
+}
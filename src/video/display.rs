@@ -0,0 +1,166 @@
+use super::*;
+use crate::events::DisplayOrientation;
+use alloc::vec::Vec;
+
+/// A display's resolution, refresh rate, and pixel format.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisplayMode {
+  pub format: PixelFormatEnum,
+  pub width: i32,
+  pub height: i32,
+  /// The refresh rate in Hz, or 0 if unknown.
+  pub refresh_rate: i32,
+}
+impl From<SDL_DisplayMode> for DisplayMode {
+  #[inline]
+  fn from(mode: SDL_DisplayMode) -> Self {
+    Self {
+      format: PixelFormatEnum(SDL_PixelFormatEnum(mode.format)),
+      width: mode.w,
+      height: mode.h,
+      refresh_rate: mode.refresh_rate,
+    }
+  }
+}
+impl From<DisplayMode> for SDL_DisplayMode {
+  #[inline]
+  fn from(mode: DisplayMode) -> Self {
+    SDL_DisplayMode {
+      format: mode.format.0 .0,
+      w: mode.width,
+      h: mode.height,
+      refresh_rate: mode.refresh_rate,
+      driverdata: core::ptr::null_mut(),
+    }
+  }
+}
+
+impl Sdl {
+  /// Gets the number of displays available.
+  #[inline]
+  pub fn num_video_displays(&self) -> Result<i32, SdlError> {
+    let n = unsafe { SDL_GetNumVideoDisplays() };
+    if n < 0 {
+      Err(get_error())
+    } else {
+      Ok(n)
+    }
+  }
+
+  /// Gets the name of a display.
+  #[inline]
+  pub fn display_name(&self, display_index: i32) -> Result<String, SdlError> {
+    let p = unsafe { SDL_GetDisplayName(display_index) };
+    if p.is_null() {
+      Err(get_error())
+    } else {
+      Ok(unsafe { string_from_sdl(p) })
+    }
+  }
+
+  /// Gets the bounds of a display, as `[x, y, w, h]`, in the global screen
+  /// space.
+  ///
+  /// The primary display is at `(0, 0)`.
+  #[inline]
+  pub fn display_bounds(&self, display_index: i32) -> Result<[i32; 4], SdlError> {
+    let mut rect = SDL_Rect::default();
+    if 0 == unsafe { SDL_GetDisplayBounds(display_index, &mut rect) } {
+      Ok([rect.x, rect.y, rect.w, rect.h])
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the bounds of a display, as `[x, y, w, h]`, less any area reserved
+  /// by the system (taskbars, menu bars, docks, etc).
+  #[inline]
+  pub fn display_usable_bounds(&self, display_index: i32) -> Result<[i32; 4], SdlError> {
+    let mut rect = SDL_Rect::default();
+    if 0 == unsafe { SDL_GetDisplayUsableBounds(display_index, &mut rect) } {
+      Ok([rect.x, rect.y, rect.w, rect.h])
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the `(diagonal, horizontal, vertical)` DPI of a display.
+  #[inline]
+  pub fn display_dpi(&self, display_index: i32) -> Result<(f32, f32, f32), SdlError> {
+    let mut ddpi = 0.0;
+    let mut hdpi = 0.0;
+    let mut vdpi = 0.0;
+    if 0 == unsafe { SDL_GetDisplayDPI(display_index, &mut ddpi, &mut hdpi, &mut vdpi) } {
+      Ok((ddpi, hdpi, vdpi))
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the orientation of a display.
+  #[inline]
+  pub fn display_orientation(&self, display_index: i32) -> DisplayOrientation {
+    DisplayOrientation::from(unsafe { SDL_GetDisplayOrientation(display_index) })
+  }
+
+  /// Gets all display modes that a display supports.
+  ///
+  /// Modes are sorted by width, height, bits per pixel, and refresh rate, from
+  /// largest to smallest.
+  #[inline]
+  pub fn display_modes(&self, display_index: i32) -> Result<Vec<DisplayMode>, SdlError> {
+    let count = unsafe { SDL_GetNumDisplayModes(display_index) };
+    if count < 0 {
+      return Err(get_error());
+    }
+    let mut out = Vec::with_capacity(count as usize);
+    for mode_index in 0..count {
+      let mut mode = SDL_DisplayMode::from(DisplayMode::default());
+      if 0 == unsafe { SDL_GetDisplayMode(display_index, mode_index, &mut mode) } {
+        out.push(DisplayMode::from(mode));
+      } else {
+        return Err(get_error());
+      }
+    }
+    Ok(out)
+  }
+
+  /// Gets the display mode of the desktop, before any fullscreen mode changes.
+  #[inline]
+  pub fn desktop_display_mode(&self, display_index: i32) -> Result<DisplayMode, SdlError> {
+    let mut mode = SDL_DisplayMode::from(DisplayMode::default());
+    if 0 == unsafe { SDL_GetDesktopDisplayMode(display_index, &mut mode) } {
+      Ok(DisplayMode::from(mode))
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the display mode that a display is currently using.
+  #[inline]
+  pub fn current_display_mode(&self, display_index: i32) -> Result<DisplayMode, SdlError> {
+    let mut mode = SDL_DisplayMode::from(DisplayMode::default());
+    if 0 == unsafe { SDL_GetCurrentDisplayMode(display_index, &mut mode) } {
+      Ok(DisplayMode::from(mode))
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the supported display mode closest to the one requested.
+  ///
+  /// A `format` or `refresh_rate` of 0 in the request means "the desktop's
+  /// value". Modes smaller than the request are never chosen.
+  #[inline]
+  pub fn closest_display_mode(
+    &self, display_index: i32, mode: DisplayMode,
+  ) -> Result<DisplayMode, SdlError> {
+    let request = SDL_DisplayMode::from(mode);
+    let mut closest = SDL_DisplayMode::from(DisplayMode::default());
+    if unsafe { SDL_GetClosestDisplayMode(display_index, &request, &mut closest) }.is_null() {
+      Err(get_error())
+    } else {
+      Ok(DisplayMode::from(closest))
+    }
+  }
+}
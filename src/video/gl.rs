@@ -1,60 +1,166 @@
 use super::*;
-
-static GL_WINDOW_ACTIVE: AtomicBool = AtomicBool::new(false);
+use core::{mem::ManuallyDrop, ptr::null_mut};
 
 /// A window powered by GL.
 ///
-/// Because GL only allows one draw context per thread, and because SDL2 isn't
-/// thread-safe by default, you can only make one of these.
+/// Each window is created with its own [`GlContext`], which is made current
+/// on that window. Any GL context can also be made current on any other GL
+/// window (with a compatible pixel format), so several windows can be drawn
+/// with a single context.
 #[repr(C)]
 pub struct GlWindow {
   win: NonNull<SDL_Window>,
-  ctx: SDL_GLContext,
+  ctx: ManuallyDrop<GlContext>,
   /// Note(Lokathor): The init is always the LAST field!
   init: Arc<SdlInit>,
 }
 impl Sdl {
-  /// You can only have one GL window active!
+  /// Makes a GL window along with a new context for it.
+  ///
+  /// The new context is current on the new window when this returns. If
+  /// [`set_gl_share_with_current_context`](Sdl::set_gl_share_with_current_context)
+  /// is enabled, it shares objects with whatever context was current before.
   #[inline]
   pub fn create_gl_window(&self, args: CreateWinArgs<'_>) -> Result<GlWindow, SdlError> {
-    match GL_WINDOW_ACTIVE.compare_exchange(false, true, Ordering::Acquire, Ordering::Acquire) {
-      Ok(_) => {
-        let title_null: String = alloc::format!("{}\0", args.title);
-        let win_p: *mut SDL_Window = unsafe {
-          SDL_CreateWindow(
-            title_null.as_ptr().cast(),
-            SDL_WINDOWPOS_CENTERED,
-            SDL_WINDOWPOS_CENTERED,
-            args.width,
-            args.height,
-            SDL_WINDOW_OPENGL.0 | args.window_flags().0,
-          )
-        };
-        match NonNull::new(win_p) {
-          Some(win) => {
-            let ctx: SDL_GLContext = unsafe { SDL_GL_CreateContext(win_p) };
-            if ctx.0.is_null() {
-              unsafe { SDL_DestroyWindow(win_p) }
-              Err(get_error())
-            } else {
-              Ok(GlWindow { win, ctx, init: self.init.clone() })
-            }
-          }
-          None => Err(get_error()),
-        }
+    let win = args.create_raw_window(SDL_WINDOW_OPENGL)?;
+    match create_raw_context(win) {
+      Ok(ctx) => {
+        let ctx = ManuallyDrop::new(GlContext { ctx, init: self.init.clone() });
+        Ok(GlWindow { win, ctx, init: self.init.clone() })
       }
-      Err(_) => Err(SdlError::new("beryllium: GL window already active.")),
+      Err(e) => {
+        unsafe { SDL_DestroyWindow(win.as_ptr()) }
+        Err(e)
+      }
+    }
+  }
+
+  /// Loads a GL library.
+  ///
+  /// Passing `None` loads the system's default library. This happens
+  /// automatically when the first GL window is made, so you only need to call
+  /// this if you want to pick a specific library. Once a library is loaded,
+  /// calling this again with `None` or the same path is fine, but a different
+  /// path is an error.
+  ///
+  /// The library stays loaded until the returned [`GlLibrary`] is dropped
+  /// *and* all GL windows and contexts are gone.
+  #[inline]
+  pub fn gl_load_library(&self, path: Option<&str>) -> Result<GlLibrary, SdlError> {
+    let path_null = path.map(|path| alloc::format!("{path}\0"));
+    let path_p = path_null.as_ref().map_or(core::ptr::null(), |p| p.as_ptr().cast());
+    if 0 == unsafe { SDL_GL_LoadLibrary(path_p) } {
+      Ok(GlLibrary { init: self.init.clone() })
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the ID of the window that has the current GL context, if any.
+  ///
+  /// Compare this against [`get_window_id`](CommonWindow::get_window_id).
+  #[inline]
+  pub fn get_current_gl_window_id(&self) -> Option<u32> {
+    let win_p = unsafe { SDL_GL_GetCurrentWindow() };
+    if win_p.is_null() {
+      None
+    } else {
+      Some(unsafe { SDL_GetWindowID(win_p) })
+    }
+  }
+
+  /// Releases the current GL context (if any) from the calling thread.
+  #[inline]
+  pub fn release_current_gl_context(&self) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_MakeCurrent(null_mut(), SDL_GLContext(null_mut())) } {
+      Ok(())
+    } else {
+      Err(get_error())
     }
   }
 }
 impl Drop for GlWindow {
   #[inline]
   fn drop(&mut self) {
-    unsafe { SDL_GL_DeleteContext(self.ctx) }
+    // the context has to go before the window it was made with.
+    unsafe { ManuallyDrop::drop(&mut self.ctx) }
     unsafe { SDL_DestroyWindow(self.win.as_ptr()) }
-    GL_WINDOW_ACTIVE.store(false, Ordering::Release);
   }
 }
+
+/// Keeps a GL library loaded, from [`Sdl::gl_load_library`].
+///
+/// SDL counts library loads, and each GL window and context holds a load of
+/// its own, so dropping this never unloads the library out from under them.
+#[must_use = "the library is unloaded when this is dropped"]
+pub struct GlLibrary {
+  #[allow(dead_code)]
+  init: Arc<SdlInit>,
+}
+impl Drop for GlLibrary {
+  #[inline]
+  fn drop(&mut self) {
+    unsafe { SDL_GL_UnloadLibrary() }
+  }
+}
+
+/// Makes a context for a GL window.
+///
+/// The context holds its own load of the GL library, which must be released
+/// with `SDL_GL_UnloadLibrary` after the context is deleted.
+fn create_raw_context(win: NonNull<SDL_Window>) -> Result<SDL_GLContext, SdlError> {
+  // The window already loaded the library, so this only adds to the count.
+  if 0 != unsafe { SDL_GL_LoadLibrary(core::ptr::null()) } {
+    return Err(get_error());
+  }
+  let ctx = unsafe { SDL_GL_CreateContext(win.as_ptr()) };
+  if ctx.0.is_null() {
+    let e = get_error();
+    unsafe { SDL_GL_UnloadLibrary() }
+    Err(e)
+  } else {
+    Ok(ctx)
+  }
+}
+
+/// A GL context.
+///
+/// A context can be made current on any [`GlWindow`] with a compatible pixel
+/// format, which is generally any window made with the same GL attributes.
+///
+/// Each context keeps the GL library loaded, so a context can safely outlive
+/// every GL window.
+pub struct GlContext {
+  ctx: SDL_GLContext,
+  /// Note(Lokathor): The init is always the LAST field!
+  #[allow(dead_code)]
+  init: Arc<SdlInit>,
+}
+impl GlContext {
+  /// Makes this context current on the given window, for the calling thread.
+  #[inline]
+  pub fn make_current(&self, win: &GlWindow) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_MakeCurrent(win.win.as_ptr(), self.ctx) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// If this is the current context of the calling thread.
+  #[inline]
+  pub fn is_current(&self) -> bool {
+    unsafe { SDL_GL_GetCurrentContext() }.0 == self.ctx.0
+  }
+}
+impl Drop for GlContext {
+  #[inline]
+  fn drop(&mut self) {
+    unsafe { SDL_GL_DeleteContext(self.ctx) }
+    unsafe { SDL_GL_UnloadLibrary() }
+  }
+}
+
 impl Deref for GlWindow {
   type Target = CommonWindow;
   #[inline]
@@ -63,6 +169,45 @@ impl Deref for GlWindow {
   }
 }
 impl GlWindow {
+  /// The context that was made along with this window.
+  #[inline]
+  pub fn context(&self) -> &GlContext {
+    &self.ctx
+  }
+
+  /// Makes this window's own context current on this window.
+  #[inline]
+  pub fn make_current(&self) -> Result<(), SdlError> {
+    self.ctx.make_current(self)
+  }
+
+  /// Makes an additional context for this window.
+  ///
+  /// The new context is current on this window when this returns.
+  ///
+  /// If `share_with` is given, the new context shares objects (textures,
+  /// buffers, etc) with that context. Otherwise the
+  /// [`set_gl_share_with_current_context`](Sdl::set_gl_share_with_current_context)
+  /// setting applies as normal.
+  #[inline]
+  pub fn create_context(&self, share_with: Option<&GlContext>) -> Result<GlContext, SdlError> {
+    let ctx: SDL_GLContext = match share_with {
+      Some(other) => {
+        other.make_current(self)?;
+        let mut old_share = 0;
+        unsafe { SDL_GL_GetAttribute(SDL_GL_SHARE_WITH_CURRENT_CONTEXT, &mut old_share) };
+        if 0 != unsafe { SDL_GL_SetAttribute(SDL_GL_SHARE_WITH_CURRENT_CONTEXT, 1) } {
+          return Err(get_error());
+        }
+        let ctx = create_raw_context(self.win);
+        unsafe { SDL_GL_SetAttribute(SDL_GL_SHARE_WITH_CURRENT_CONTEXT, old_share) };
+        ctx?
+      }
+      None => create_raw_context(self.win)?,
+    };
+    Ok(GlContext { ctx, init: self.init.clone() })
+  }
+
   #[inline]
   pub fn get_drawable_size(&self) -> (i32, i32) {
     let mut width = 0_i32;
@@ -82,13 +227,24 @@ impl GlWindow {
     unsafe { SDL_GL_ExtensionSupported(ext_null.as_ptr().cast()) }.into()
   }
 
-  /// ## Safety
-  /// * The pointer must point to a zero-terminated string that names a GL
-  ///   command that's supported by the current GL context's version and
-  ///   supported extensions.
+  /// Gets the address of a GL function, or `None` if it's not available.
+  ///
+  /// Some platforms give back a non-null address even for functions that the
+  /// current context doesn't support, so check the context's version and
+  /// extensions before calling anything that you look up.
+  #[inline]
+  pub fn get_proc_address(&self, name: &str) -> Option<NonNull<c_void>> {
+    let name_null = alloc::format!("{name}\0");
+    NonNull::new(unsafe { SDL_GL_GetProcAddress(name_null.as_ptr().cast()) })
+  }
+
+  /// Gives a loader function in the shape that GL binding crates expect.
+  ///
+  /// Functions that aren't available are given as null. For example,
+  /// `gl::load_with(win.gl_loader())`.
   #[inline]
-  pub unsafe fn get_proc_address(&self, name: *const u8) -> *mut c_void {
-    SDL_GL_GetProcAddress(name.cast())
+  pub fn gl_loader(&self) -> impl Fn(&str) -> *const c_void + '_ {
+    |name| self.get_proc_address(name).map_or(core::ptr::null(), |p| p.as_ptr().cast_const())
   }
 
   #[inline]
@@ -99,11 +255,33 @@ impl GlWindow {
       Err(get_error())
     }
   }
+
+  /// Gets the swap interval of the current context.
+  #[inline]
+  pub fn get_swap_interval(&self) -> GlSwapInterval {
+    match unsafe { SDL_GL_GetSwapInterval() } {
+      0 => GlSwapInterval::Immediate,
+      i if i < 0 => GlSwapInterval::AdaptiveVsync,
+      _ => GlSwapInterval::Vsync,
+    }
+  }
+
+  /// Tries to set adaptive vsync, and falls back to regular vsync if adaptive
+  /// vsync isn't supported.
+  ///
+  /// Gives the interval that was actually applied.
+  #[inline]
+  pub fn set_swap_interval_with_fallback(&self) -> Result<GlSwapInterval, SdlError> {
+    match self.set_swap_interval(GlSwapInterval::AdaptiveVsync) {
+      Ok(()) => Ok(GlSwapInterval::AdaptiveVsync),
+      Err(_) => self.set_swap_interval(GlSwapInterval::Vsync).map(|()| GlSwapInterval::Vsync),
+    }
+  }
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
-pub struct GlContextFlags(SDL_GLcontextFlag);
+pub struct GlContextFlags(pub(crate) SDL_GLcontextFlag);
 impl GlContextFlags {
   pub const DEBUG: Self = Self(SDL_GL_CONTEXT_DEBUG_FLAG);
   pub const FORWARD_COMPATIBLE: Self = Self(SDL_GL_CONTEXT_FORWARD_COMPATIBLE_FLAG);
@@ -140,6 +318,55 @@ pub enum GlSwapInterval {
   AdaptiveVsync = -1,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(i32)]
+pub enum GlReleaseBehavior {
+  /// Nothing happens.
+  None = SDL_GL_CONTEXT_RELEASE_BEHAVIOR_NONE.0 as i32,
+  /// Pending commands are flushed.
+  Flush = SDL_GL_CONTEXT_RELEASE_BEHAVIOR_FLUSH.0 as i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(i32)]
+pub enum GlResetNotification {
+  NoNotification = SDL_GL_CONTEXT_RESET_NO_NOTIFICATION.0 as i32,
+  LoseContext = SDL_GL_CONTEXT_RESET_LOSE_CONTEXT.0 as i32,
+}
+
+/// The GL attributes, for use with [`get_gl_attribute`](Sdl::get_gl_attribute).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u32)]
+pub enum GlAttr {
+  RedSize = SDL_GL_RED_SIZE.0,
+  GreenSize = SDL_GL_GREEN_SIZE.0,
+  BlueSize = SDL_GL_BLUE_SIZE.0,
+  AlphaSize = SDL_GL_ALPHA_SIZE.0,
+  BufferSize = SDL_GL_BUFFER_SIZE.0,
+  DoubleBuffer = SDL_GL_DOUBLEBUFFER.0,
+  DepthSize = SDL_GL_DEPTH_SIZE.0,
+  StencilSize = SDL_GL_STENCIL_SIZE.0,
+  AccumRedSize = SDL_GL_ACCUM_RED_SIZE.0,
+  AccumGreenSize = SDL_GL_ACCUM_GREEN_SIZE.0,
+  AccumBlueSize = SDL_GL_ACCUM_BLUE_SIZE.0,
+  AccumAlphaSize = SDL_GL_ACCUM_ALPHA_SIZE.0,
+  Stereo = SDL_GL_STEREO.0,
+  MultisampleBuffers = SDL_GL_MULTISAMPLEBUFFERS.0,
+  MultisampleSamples = SDL_GL_MULTISAMPLESAMPLES.0,
+  AcceleratedVisual = SDL_GL_ACCELERATED_VISUAL.0,
+  RetainedBacking = SDL_GL_RETAINED_BACKING.0,
+  ContextMajorVersion = SDL_GL_CONTEXT_MAJOR_VERSION.0,
+  ContextMinorVersion = SDL_GL_CONTEXT_MINOR_VERSION.0,
+  ContextEgl = SDL_GL_CONTEXT_EGL.0,
+  ContextFlags = SDL_GL_CONTEXT_FLAGS.0,
+  ContextProfileMask = SDL_GL_CONTEXT_PROFILE_MASK.0,
+  ShareWithCurrentContext = SDL_GL_SHARE_WITH_CURRENT_CONTEXT.0,
+  FramebufferSrgbCapable = SDL_GL_FRAMEBUFFER_SRGB_CAPABLE.0,
+  ContextReleaseBehavior = SDL_GL_CONTEXT_RELEASE_BEHAVIOR.0,
+  ContextResetNotification = SDL_GL_CONTEXT_RESET_NOTIFICATION.0,
+  ContextNoError = SDL_GL_CONTEXT_NO_ERROR.0,
+}
+
 impl Sdl {
   /// Sets the *minimum* number of depth buffer bits (default=16).
   #[inline]
@@ -227,6 +454,17 @@ impl Sdl {
     }
   }
 
+  /// If newly made contexts should share objects with the current context
+  /// (default=false).
+  #[inline]
+  pub fn set_gl_share_with_current_context(&self, share: bool) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_SHARE_WITH_CURRENT_CONTEXT, i32::from(share)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
   #[inline]
   pub fn set_gl_framebuffer_srgb_capable(&self, capable: bool) -> Result<(), SdlError> {
     if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_FRAMEBUFFER_SRGB_CAPABLE, i32::from(capable)) } {
@@ -235,4 +473,210 @@ impl Sdl {
       Err(get_error())
     }
   }
+
+  /// Sets the *minimum* number of red channel bits (default=3).
+  #[inline]
+  pub fn set_gl_red_bits(&self, count: u8) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_RED_SIZE, i32::from(count)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the *minimum* number of green channel bits (default=3).
+  #[inline]
+  pub fn set_gl_green_bits(&self, count: u8) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_GREEN_SIZE, i32::from(count)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the *minimum* number of blue channel bits (default=2).
+  #[inline]
+  pub fn set_gl_blue_bits(&self, count: u8) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_BLUE_SIZE, i32::from(count)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the *minimum* number of alpha channel bits (default=0).
+  #[inline]
+  pub fn set_gl_alpha_bits(&self, count: u8) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_ALPHA_SIZE, i32::from(count)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the *minimum* number of total framebuffer bits (default=0).
+  #[inline]
+  pub fn set_gl_buffer_bits(&self, count: u8) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_BUFFER_SIZE, i32::from(count)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// If the framebuffer should be double buffered (default=true).
+  #[inline]
+  pub fn set_gl_double_buffer(&self, double: bool) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_DOUBLEBUFFER, i32::from(double)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the *minimum* number of accumulation buffer red bits (default=0).
+  #[inline]
+  pub fn set_gl_accum_red_bits(&self, count: u8) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_ACCUM_RED_SIZE, i32::from(count)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the *minimum* number of accumulation buffer green bits (default=0).
+  #[inline]
+  pub fn set_gl_accum_green_bits(&self, count: u8) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_ACCUM_GREEN_SIZE, i32::from(count)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the *minimum* number of accumulation buffer blue bits (default=0).
+  #[inline]
+  pub fn set_gl_accum_blue_bits(&self, count: u8) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_ACCUM_BLUE_SIZE, i32::from(count)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the *minimum* number of accumulation buffer alpha bits (default=0).
+  #[inline]
+  pub fn set_gl_accum_alpha_bits(&self, count: u8) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_ACCUM_ALPHA_SIZE, i32::from(count)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// If the framebuffer should use stereo 3D (default=false).
+  #[inline]
+  pub fn set_gl_stereo(&self, stereo: bool) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_STEREO, i32::from(stereo)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Requires (`Some(true)`) or forbids (`Some(false)`) hardware acceleration.
+  ///
+  /// By default (`None`) either is allowed.
+  #[inline]
+  pub fn set_gl_accelerated_visual(&self, accelerated: Option<bool>) -> Result<(), SdlError> {
+    if 0
+      == unsafe {
+        SDL_GL_SetAttribute(SDL_GL_ACCELERATED_VISUAL, accelerated.map_or(-1, i32::from))
+      }
+    {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Use retained backing for the framebuffer (default=true, only used on iOS).
+  #[inline]
+  pub fn set_gl_retained_backing(&self, retained: bool) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_RETAINED_BACKING, i32::from(retained)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Use EGL to make the context (default=false).
+  ///
+  /// This is deprecated by SDL in favor of using [`GlProfile::ES`].
+  #[inline]
+  pub fn set_gl_context_egl(&self, egl: bool) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_CONTEXT_EGL, i32::from(egl)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// What happens when a context is released from being current
+  /// (default=[`Flush`](GlReleaseBehavior::Flush)).
+  #[inline]
+  pub fn set_gl_release_behavior(&self, behavior: GlReleaseBehavior) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_CONTEXT_RELEASE_BEHAVIOR, behavior as i32) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// How the context reacts to a GPU reset
+  /// (default=[`NoNotification`](GlResetNotification::NoNotification)).
+  #[inline]
+  pub fn set_gl_reset_notification(
+    &self, notification: GlResetNotification,
+  ) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_CONTEXT_RESET_NOTIFICATION, notification as i32) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Makes a context that doesn't report errors (default=false).
+  ///
+  /// GL errors become undefined behavior in such a context, so only use this
+  /// with code that's already known to be error free.
+  #[inline]
+  pub fn set_gl_no_error(&self, no_error: bool) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_GL_SetAttribute(SDL_GL_CONTEXT_NO_ERROR, i32::from(no_error)) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the value of a GL attribute.
+  ///
+  /// Once a context has been made, most attributes give the actual value
+  /// provided by the current context, which can differ from what was asked
+  /// for.
+  #[inline]
+  pub fn get_gl_attribute(&self, attr: GlAttr) -> Result<i32, SdlError> {
+    let mut value = 0;
+    if 0 == unsafe { SDL_GL_GetAttribute(SDL_GLattr(attr as u32), &mut value) } {
+      Ok(value)
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Resets all GL attributes to their default values.
+  #[inline]
+  pub fn reset_gl_attributes(&self) {
+    unsafe { SDL_GL_ResetAttributes() }
+  }
 }
@@ -0,0 +1,276 @@
+use super::*;
+
+/// All the settings that go into making a GL window and context.
+///
+/// The default values are SDL's own defaults. Pass this to
+/// [`create_gl_window_with_config`](Sdl::create_gl_window_with_config).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlConfig {
+  /// The *minimum* context version, as `(major, minor)`.
+  pub version: Option<(u8, u8)>,
+  /// The required context profile.
+  pub profile: Option<GlProfile>,
+  pub flags: GlContextFlags,
+  pub red_bits: u8,
+  pub green_bits: u8,
+  pub blue_bits: u8,
+  pub alpha_bits: u8,
+  pub depth_bits: u8,
+  pub stencil_bits: u8,
+  pub multisample_buffers: u8,
+  pub multisample_count: u8,
+  pub double_buffer: bool,
+  pub stereo: bool,
+  pub accelerated_visual: Option<bool>,
+  pub framebuffer_srgb_capable: bool,
+  pub share_with_current_context: bool,
+  pub release_behavior: GlReleaseBehavior,
+  pub reset_notification: GlResetNotification,
+  pub no_error: bool,
+}
+impl Default for GlConfig {
+  #[inline]
+  fn default() -> Self {
+    Self {
+      version: None,
+      profile: None,
+      flags: GlContextFlags::default(),
+      red_bits: 3,
+      green_bits: 3,
+      blue_bits: 2,
+      alpha_bits: 0,
+      depth_bits: 16,
+      stencil_bits: 0,
+      multisample_buffers: 0,
+      multisample_count: 0,
+      double_buffer: true,
+      stereo: false,
+      accelerated_visual: None,
+      framebuffer_srgb_capable: false,
+      share_with_current_context: false,
+      release_behavior: GlReleaseBehavior::Flush,
+      reset_notification: GlResetNotification::NoNotification,
+      no_error: false,
+    }
+  }
+}
+impl GlConfig {
+  fn apply(&self, sdl: &Sdl) -> Result<(), SdlError> {
+    sdl.reset_gl_attributes();
+    if let Some((major, minor)) = self.version {
+      sdl.set_gl_context_major_version(major)?;
+      sdl.set_gl_context_minor_version(minor)?;
+    }
+    if let Some(profile) = self.profile {
+      sdl.set_gl_profile(profile)?;
+    }
+    sdl.set_gl_context_flags(self.flags)?;
+    sdl.set_gl_red_bits(self.red_bits)?;
+    sdl.set_gl_green_bits(self.green_bits)?;
+    sdl.set_gl_blue_bits(self.blue_bits)?;
+    sdl.set_gl_alpha_bits(self.alpha_bits)?;
+    sdl.set_gl_depth_bits(self.depth_bits)?;
+    sdl.set_gl_stencil_bits(self.stencil_bits)?;
+    sdl.set_gl_multisample_buffers(self.multisample_buffers)?;
+    sdl.set_gl_multisample_count(self.multisample_count)?;
+    sdl.set_gl_double_buffer(self.double_buffer)?;
+    sdl.set_gl_stereo(self.stereo)?;
+    sdl.set_gl_accelerated_visual(self.accelerated_visual)?;
+    sdl.set_gl_framebuffer_srgb_capable(self.framebuffer_srgb_capable)?;
+    sdl.set_gl_share_with_current_context(self.share_with_current_context)?;
+    sdl.set_gl_release_behavior(self.release_behavior)?;
+    sdl.set_gl_reset_notification(self.reset_notification)?;
+    sdl.set_gl_no_error(self.no_error)?;
+    Ok(())
+  }
+}
+
+/// The settings of a GL context, as read back after making it.
+///
+/// Each value is `None` if it couldn't be read. Not every context supports
+/// every query: GLES has no stereo or double buffer query, and the release
+/// behavior and reset notification need GL 4.5 or an extension.
+///
+/// The `version` and `profile` come from the context itself. Everything else
+/// is as reported by SDL, and some values (such as `flags`) are just what was
+/// requested.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GlObtained {
+  pub version: Option<(u8, u8)>,
+  pub profile: Option<GlProfile>,
+  pub flags: Option<GlContextFlags>,
+  pub red_bits: Option<u8>,
+  pub green_bits: Option<u8>,
+  pub blue_bits: Option<u8>,
+  pub alpha_bits: Option<u8>,
+  pub depth_bits: Option<u8>,
+  pub stencil_bits: Option<u8>,
+  pub multisample_buffers: Option<u8>,
+  pub multisample_count: Option<u8>,
+  pub double_buffer: Option<bool>,
+  pub stereo: Option<bool>,
+  pub accelerated_visual: Option<bool>,
+  pub framebuffer_srgb_capable: Option<bool>,
+  pub share_with_current_context: Option<bool>,
+  pub release_behavior: Option<GlReleaseBehavior>,
+  pub reset_notification: Option<GlResetNotification>,
+  pub no_error: Option<bool>,
+}
+impl GlObtained {
+  /// Reads back the settings of the current context.
+  fn read_current(sdl: &Sdl, win: &GlWindow) -> Self {
+    let get = |attr| sdl.get_gl_attribute(attr).ok();
+    let get_u8 = |attr| get(attr).map(|v| v.clamp(0, 255) as u8);
+    let get_bool = |attr| get(attr).map(|v| v != 0);
+    let (version, profile) = match current_version_and_profile(win) {
+      Some((version, profile)) => (Some(version), Some(profile)),
+      None => (None, None),
+    };
+    Self {
+      version,
+      profile,
+      flags: get(GlAttr::ContextFlags).map(|v| GlContextFlags(SDL_GLcontextFlag(v as u32))),
+      red_bits: get_u8(GlAttr::RedSize),
+      green_bits: get_u8(GlAttr::GreenSize),
+      blue_bits: get_u8(GlAttr::BlueSize),
+      alpha_bits: get_u8(GlAttr::AlphaSize),
+      depth_bits: get_u8(GlAttr::DepthSize),
+      stencil_bits: get_u8(GlAttr::StencilSize),
+      multisample_buffers: get_u8(GlAttr::MultisampleBuffers),
+      multisample_count: get_u8(GlAttr::MultisampleSamples),
+      double_buffer: get_bool(GlAttr::DoubleBuffer),
+      stereo: get_bool(GlAttr::Stereo),
+      accelerated_visual: get_bool(GlAttr::AcceleratedVisual),
+      framebuffer_srgb_capable: get_bool(GlAttr::FramebufferSrgbCapable),
+      share_with_current_context: get_bool(GlAttr::ShareWithCurrentContext),
+      release_behavior: get(GlAttr::ContextReleaseBehavior).map(|v| {
+        if v == GlReleaseBehavior::None as i32 {
+          GlReleaseBehavior::None
+        } else {
+          GlReleaseBehavior::Flush
+        }
+      }),
+      reset_notification: get(GlAttr::ContextResetNotification).map(|v| {
+        if v == GlResetNotification::LoseContext as i32 {
+          GlResetNotification::LoseContext
+        } else {
+          GlResetNotification::NoNotification
+        }
+      }),
+      no_error: get_bool(GlAttr::ContextNoError),
+    }
+  }
+}
+
+/// What was asked for when making a GL window, and what was actually given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlReport {
+  pub requested: GlConfig,
+  pub obtained: GlObtained,
+}
+
+impl Sdl {
+  /// Makes a GL window using all the settings of a [`GlConfig`].
+  ///
+  /// This replaces all GL attributes that were set before.
+  ///
+  /// Errors if the new context doesn't meet the minimum version, or isn't the
+  /// profile that was asked for. The other settings are only reported.
+  #[inline]
+  pub fn create_gl_window_with_config(
+    &self, args: CreateWinArgs<'_>, config: &GlConfig,
+  ) -> Result<(GlWindow, GlReport), SdlError> {
+    config.apply(self)?;
+    let win = self.create_gl_window(args)?;
+    let obtained = GlObtained::read_current(self, &win);
+    if let Some(requested) = config.version {
+      match obtained.version {
+        Some(version) if version >= requested => (),
+        _ => {
+          return Err(SdlError::new(&alloc::format!(
+            "beryllium: requested GL version {requested:?}, obtained {:?}",
+            obtained.version
+          )))
+        }
+      }
+    }
+    if config.profile.is_some() && config.profile != obtained.profile {
+      return Err(SdlError::new(&alloc::format!(
+        "beryllium: requested GL profile {:?}, obtained {:?}",
+        config.profile,
+        obtained.profile
+      )));
+    }
+    Ok((win, GlReport { requested: *config, obtained }))
+  }
+}
+
+const GL_VERSION: u32 = 0x1F02;
+const GL_CONTEXT_PROFILE_MASK: u32 = 0x9126;
+const GL_CONTEXT_CORE_PROFILE_BIT: i32 = 0x1;
+
+/// Asks the current context for its actual version and profile.
+///
+/// SDL only reports back the requested values for these.
+fn current_version_and_profile(win: &GlWindow) -> Option<((u8, u8), GlProfile)> {
+  type GetString = unsafe extern "system" fn(u32) -> *const c_char;
+  type GetIntegerv = unsafe extern "system" fn(u32, *mut i32);
+  let get_string: GetString = unsafe {
+    core::mem::transmute::<*mut c_void, GetString>(win.get_proc_address("glGetString")?.as_ptr())
+  };
+  let version_p = unsafe { get_string(GL_VERSION) };
+  if version_p.is_null() {
+    return None;
+  }
+  let version_string = unsafe { string_from_sdl(version_p) };
+  let (is_es, major, minor) = parse_gl_version(&version_string)?;
+  let profile = if is_es {
+    GlProfile::ES
+  } else if (major, minor) >= (3, 2) {
+    let get_integerv: GetIntegerv = unsafe {
+      core::mem::transmute::<*mut c_void, GetIntegerv>(
+        win.get_proc_address("glGetIntegerv")?.as_ptr(),
+      )
+    };
+    let mut mask = 0;
+    unsafe { get_integerv(GL_CONTEXT_PROFILE_MASK, &mut mask) };
+    if mask & GL_CONTEXT_CORE_PROFILE_BIT != 0 {
+      GlProfile::Core
+    } else {
+      GlProfile::Compatibility
+    }
+  } else {
+    // Contexts from before profiles existed act like compatibility contexts.
+    GlProfile::Compatibility
+  };
+  Some(((major, minor), profile))
+}
+
+/// Parses a `GL_VERSION` string into `(is_es, major, minor)`.
+///
+/// Desktop GL gives "major.minor[.release] [vendor info]", and ES gives
+/// "OpenGL ES[-profile] major.minor [vendor info]".
+fn parse_gl_version(version_string: &str) -> Option<(bool, u8, u8)> {
+  let (is_es, numbers) = match version_string.strip_prefix("OpenGL ES") {
+    Some(rest) => (true, rest.trim_start_matches(|c: char| c != ' ').trim_start()),
+    None => (false, version_string),
+  };
+  let mut parts = numbers.split(|c: char| !c.is_ascii_digit());
+  let major: u8 = parts.next()?.parse().ok()?;
+  let minor: u8 = parts.next()?.parse().ok()?;
+  Some((is_es, major, minor))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::parse_gl_version;
+
+  #[test]
+  fn test_parse_gl_version() {
+    assert_eq!(parse_gl_version("4.6.0 NVIDIA 535.183.01"), Some((false, 4, 6)));
+    assert_eq!(parse_gl_version("3.3 (Core Profile) Mesa 23.2.1"), Some((false, 3, 3)));
+    assert_eq!(parse_gl_version("OpenGL ES 3.2 Mesa 23.2.1"), Some((true, 3, 2)));
+    assert_eq!(parse_gl_version("OpenGL ES-CM 1.1"), Some((true, 1, 1)));
+    assert_eq!(parse_gl_version(""), None);
+  }
+}
@@ -1,19 +1,20 @@
 use crate::{
   error::{get_error, SdlError},
   init::SdlInit,
+  string_from_sdl,
+  surface::Surface,
   Sdl,
 };
 use alloc::{string::String, sync::Arc};
-use core::{
-  ops::Deref,
-  ptr::NonNull,
-  sync::atomic::{AtomicBool, Ordering},
-};
+use core::{ops::Deref, ptr::NonNull};
 use fermium::prelude::*;
 
 mod gl;
 pub use gl::*;
 
+mod gl_config;
+pub use gl_config::*;
+
 #[cfg(feature = "vulkan")]
 mod vk;
 #[cfg(feature = "vulkan")]
@@ -22,13 +23,75 @@ pub use vk::*;
 mod renderer;
 pub use renderer::*;
 
+mod display;
+pub use display::*;
+
+mod window;
+pub use window::*;
+
+mod syswm;
+pub use syswm::*;
+
+#[cfg(feature = "raw_window_handle")]
+mod rwh;
+
+/// Where a new window is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowPosition {
+  /// Let the OS pick.
+  Undefined,
+  /// Centered on the primary display.
+  Centered,
+  /// Centered on the display with the given index.
+  CenteredOnDisplay(u16),
+  /// Upper left corner at the given position, in logical "screen units".
+  At(i32, i32),
+}
+impl WindowPosition {
+  #[inline]
+  fn to_xy(self) -> (c_int, c_int) {
+    match self {
+      WindowPosition::Undefined => (SDL_WINDOWPOS_UNDEFINED, SDL_WINDOWPOS_UNDEFINED),
+      WindowPosition::Centered => (SDL_WINDOWPOS_CENTERED, SDL_WINDOWPOS_CENTERED),
+      WindowPosition::CenteredOnDisplay(display) => {
+        let pos = SDL_WINDOWPOS_CENTERED | c_int::from(display);
+        (pos, pos)
+      }
+      WindowPosition::At(x, y) => (x, y),
+    }
+  }
+}
+
+/// The kind of window to create.
+///
+/// Everything except `Normal` is currently only supported on X11.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowKind {
+  Normal,
+  /// A utility window, such as a tool palette.
+  Utility,
+  /// A tooltip.
+  Tooltip,
+  /// A popup menu.
+  PopupMenu,
+}
+
 pub struct CreateWinArgs<'s> {
   pub title: &'s str,
   pub width: i32,
   pub height: i32,
+  pub position: WindowPosition,
   pub allow_high_dpi: bool,
   pub borderless: bool,
   pub resizable: bool,
+  pub hidden: bool,
+  pub minimized: bool,
+  pub maximized: bool,
+  pub always_on_top: bool,
+  pub skip_taskbar: bool,
+  pub input_grabbed: bool,
+  pub kind: WindowKind,
+  pub fullscreen: FullscreenMode,
 }
 impl CreateWinArgs<'_> {
   fn window_flags(&self) -> SDL_WindowFlags {
@@ -42,8 +105,54 @@ impl CreateWinArgs<'_> {
     if self.resizable {
       out |= SDL_WINDOW_RESIZABLE.0
     }
+    if self.hidden {
+      out |= SDL_WINDOW_HIDDEN.0
+    }
+    if self.minimized {
+      out |= SDL_WINDOW_MINIMIZED.0
+    }
+    if self.maximized {
+      out |= SDL_WINDOW_MAXIMIZED.0
+    }
+    if self.always_on_top {
+      out |= SDL_WINDOW_ALWAYS_ON_TOP.0
+    }
+    if self.skip_taskbar {
+      out |= SDL_WINDOW_SKIP_TASKBAR.0
+    }
+    if self.input_grabbed {
+      out |= SDL_WINDOW_INPUT_GRABBED.0
+    }
+    out |= match self.kind {
+      WindowKind::Normal => 0,
+      WindowKind::Utility => SDL_WINDOW_UTILITY.0,
+      WindowKind::Tooltip => SDL_WINDOW_TOOLTIP.0,
+      WindowKind::PopupMenu => SDL_WINDOW_POPUP_MENU.0,
+    };
+    out |= match self.fullscreen {
+      FullscreenMode::Windowed => 0,
+      FullscreenMode::Desktop => SDL_WINDOW_FULLSCREEN_DESKTOP.0,
+      FullscreenMode::Exclusive => SDL_WINDOW_FULLSCREEN.0,
+    };
     SDL_WindowFlags(out)
   }
+
+  /// Creates the SDL window, adding `api_flags` to the flags from the args.
+  fn create_raw_window(&self, api_flags: SDL_WindowFlags) -> Result<NonNull<SDL_Window>, SdlError> {
+    let title_null: String = alloc::format!("{}\0", self.title);
+    let (x, y) = self.position.to_xy();
+    let win_p: *mut SDL_Window = unsafe {
+      SDL_CreateWindow(
+        title_null.as_ptr().cast(),
+        x,
+        y,
+        self.width,
+        self.height,
+        api_flags.0 | self.window_flags().0,
+      )
+    };
+    NonNull::new(win_p).ok_or_else(get_error)
+  }
 }
 impl Default for CreateWinArgs<'_> {
   #[inline]
@@ -52,9 +161,18 @@ impl Default for CreateWinArgs<'_> {
       title: "DefaultName",
       width: 800,
       height: 600,
+      position: WindowPosition::Centered,
       allow_high_dpi: true,
       borderless: false,
       resizable: false,
+      hidden: false,
+      minimized: false,
+      maximized: false,
+      always_on_top: false,
+      skip_taskbar: false,
+      input_grabbed: false,
+      kind: WindowKind::Normal,
+      fullscreen: FullscreenMode::Windowed,
     }
   }
 }
@@ -62,7 +180,7 @@ impl Default for CreateWinArgs<'_> {
 /// Provides the methods common to all types of window.
 #[repr(C)]
 pub struct CommonWindow {
-  win: NonNull<SDL_Window>,
+  pub(crate) win: NonNull<SDL_Window>,
 }
 impl CommonWindow {
   /// Gets the window size in logical "screen units".
@@ -88,4 +206,283 @@ impl CommonWindow {
     let new_title = alloc::format!("{title}\0");
     unsafe { SDL_SetWindowTitle(self.win.as_ptr(), new_title.as_ptr().cast()) }
   }
+
+  #[inline]
+  pub fn get_title(&self) -> String {
+    unsafe { string_from_sdl(SDL_GetWindowTitle(self.win.as_ptr())) }
+  }
+
+  /// Gets the ID of this window.
+  ///
+  /// This is the `win_id` value used by window related events.
+  #[inline]
+  pub fn get_window_id(&self) -> u32 {
+    unsafe { SDL_GetWindowID(self.win.as_ptr()) }
+  }
+
+  #[inline]
+  pub fn get_window_flags(&self) -> WindowFlags {
+    WindowFlags(SDL_WindowFlags(unsafe { SDL_GetWindowFlags(self.win.as_ptr()) }))
+  }
+
+  /// Gets the position of the window's upper left corner, in logical "screen
+  /// units".
+  #[inline]
+  pub fn get_window_position(&self) -> (i32, i32) {
+    let mut x = 0_i32;
+    let mut y = 0_i32;
+    unsafe { SDL_GetWindowPosition(self.win.as_ptr(), &mut x, &mut y) }
+    (x, y)
+  }
+
+  /// Sets the position of the window's upper left corner, in logical "screen
+  /// units".
+  #[inline]
+  pub fn set_window_position(&self, x: i32, y: i32) {
+    unsafe { SDL_SetWindowPosition(self.win.as_ptr(), x, y) }
+  }
+
+  #[inline]
+  pub fn get_window_minimum_size(&self) -> (i32, i32) {
+    let mut width = 0_i32;
+    let mut height = 0_i32;
+    unsafe { SDL_GetWindowMinimumSize(self.win.as_ptr(), &mut width, &mut height) }
+    (width, height)
+  }
+
+  /// Sets the smallest size the user can resize the window to.
+  #[inline]
+  pub fn set_window_minimum_size(&self, width: i32, height: i32) {
+    unsafe { SDL_SetWindowMinimumSize(self.win.as_ptr(), width, height) }
+  }
+
+  #[inline]
+  pub fn get_window_maximum_size(&self) -> (i32, i32) {
+    let mut width = 0_i32;
+    let mut height = 0_i32;
+    unsafe { SDL_GetWindowMaximumSize(self.win.as_ptr(), &mut width, &mut height) }
+    (width, height)
+  }
+
+  /// Sets the largest size the user can resize the window to.
+  #[inline]
+  pub fn set_window_maximum_size(&self, width: i32, height: i32) {
+    unsafe { SDL_SetWindowMaximumSize(self.win.as_ptr(), width, height) }
+  }
+
+  #[inline]
+  pub fn show_window(&self) {
+    unsafe { SDL_ShowWindow(self.win.as_ptr()) }
+  }
+
+  #[inline]
+  pub fn hide_window(&self) {
+    unsafe { SDL_HideWindow(self.win.as_ptr()) }
+  }
+
+  /// Raises the window above other windows and gives it input focus.
+  #[inline]
+  pub fn raise_window(&self) {
+    unsafe { SDL_RaiseWindow(self.win.as_ptr()) }
+  }
+
+  #[inline]
+  pub fn minimize_window(&self) {
+    unsafe { SDL_MinimizeWindow(self.win.as_ptr()) }
+  }
+
+  #[inline]
+  pub fn maximize_window(&self) {
+    unsafe { SDL_MaximizeWindow(self.win.as_ptr()) }
+  }
+
+  /// Restores the size and position of a minimized or maximized window.
+  #[inline]
+  pub fn restore_window(&self) {
+    unsafe { SDL_RestoreWindow(self.win.as_ptr()) }
+  }
+
+  /// Adds or removes the window's border (title bar, frame, etc).
+  ///
+  /// This has no effect on fullscreen windows.
+  #[inline]
+  pub fn set_bordered(&self, bordered: bool) {
+    unsafe { SDL_SetWindowBordered(self.win.as_ptr(), bordered.into()) }
+  }
+
+  /// Sets if the user can resize the window.
+  ///
+  /// This has no effect on fullscreen windows.
+  #[inline]
+  pub fn set_resizable(&self, resizable: bool) {
+    unsafe { SDL_SetWindowResizable(self.win.as_ptr(), resizable.into()) }
+  }
+
+  #[inline]
+  pub fn set_always_on_top(&self, on_top: bool) {
+    unsafe { SDL_SetWindowAlwaysOnTop(self.win.as_ptr(), on_top.into()) }
+  }
+
+  /// Sets the window's icon.
+  ///
+  /// SDL copies the pixels, so the surface can be dropped afterward.
+  #[inline]
+  pub fn set_icon(&self, icon: &Surface) {
+    unsafe { SDL_SetWindowIcon(self.win.as_ptr(), icon.surf.as_ptr()) }
+  }
+
+  /// Gets SDL's window manager info about the window.
+  pub(crate) fn raw_wm_info(&self) -> Result<SDL_SysWMinfo, SdlError> {
+    let mut info = SDL_SysWMinfo::default();
+    SDL_VERSION(&mut info.version);
+    if SDL_TRUE == unsafe { SDL_GetWindowWMInfo(self.win.as_ptr(), &mut info) } {
+      Ok(info)
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Changes the window's fullscreen state.
+  #[inline]
+  pub fn set_fullscreen(&self, mode: FullscreenMode) -> Result<(), SdlError> {
+    let flags = match mode {
+      FullscreenMode::Windowed => 0,
+      FullscreenMode::Desktop => SDL_WINDOW_FULLSCREEN_DESKTOP.0,
+      FullscreenMode::Exclusive => SDL_WINDOW_FULLSCREEN.0,
+    };
+    if 0 == unsafe { SDL_SetWindowFullscreen(self.win.as_ptr(), flags) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the display mode used when the window is in exclusive fullscreen.
+  #[inline]
+  pub fn get_display_mode(&self) -> Result<DisplayMode, SdlError> {
+    let mut mode = SDL_DisplayMode::from(DisplayMode::default());
+    if 0 == unsafe { SDL_GetWindowDisplayMode(self.win.as_ptr(), &mut mode) } {
+      Ok(DisplayMode::from(mode))
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the display mode used when the window is in exclusive fullscreen.
+  ///
+  /// SDL will pick the closest mode that the display supports. Passing `None`
+  /// uses the window's size and the desktop's format and refresh rate.
+  #[inline]
+  pub fn set_display_mode(&self, mode: Option<DisplayMode>) -> Result<(), SdlError> {
+    let ret = match mode {
+      Some(mode) => {
+        let mode = SDL_DisplayMode::from(mode);
+        unsafe { SDL_SetWindowDisplayMode(self.win.as_ptr(), &mode) }
+      }
+      None => unsafe { SDL_SetWindowDisplayMode(self.win.as_ptr(), core::ptr::null()) },
+    };
+    if 0 == ret {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets the index of the display that the center of the window is on.
+  #[inline]
+  pub fn get_display_index(&self) -> Result<i32, SdlError> {
+    let index = unsafe { SDL_GetWindowDisplayIndex(self.win.as_ptr()) };
+    if index < 0 {
+      Err(get_error())
+    } else {
+      Ok(index)
+    }
+  }
+
+  /// Gets the window's opacity, from `0.0` (transparent) to `1.0` (opaque).
+  #[inline]
+  pub fn get_window_opacity(&self) -> Result<f32, SdlError> {
+    let mut opacity = 1.0;
+    if 0 == unsafe { SDL_GetWindowOpacity(self.win.as_ptr(), &mut opacity) } {
+      Ok(opacity)
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Sets the window's opacity, from `0.0` (transparent) to `1.0` (opaque).
+  ///
+  /// Errors if the platform doesn't support window opacity.
+  #[inline]
+  pub fn set_window_opacity(&self, opacity: f32) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_SetWindowOpacity(self.win.as_ptr(), opacity) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+}
+
+/// How a window should be shown on its display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FullscreenMode {
+  /// A normal window.
+  Windowed,
+  /// A borderless window covering the whole display, at the desktop's current
+  /// resolution ("borderless fullscreen").
+  Desktop,
+  /// Takes over the display, changing the video mode to the window's
+  /// [display mode](CommonWindow::set_display_mode).
+  Exclusive,
+}
+
+/// The state flags of a window.
+///
+/// See [`get_window_flags`](CommonWindow::get_window_flags).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct WindowFlags(SDL_WindowFlags);
+impl WindowFlags {
+  pub const FULLSCREEN: Self = Self(SDL_WINDOW_FULLSCREEN);
+  pub const FULLSCREEN_DESKTOP: Self = Self(SDL_WINDOW_FULLSCREEN_DESKTOP);
+  pub const OPENGL: Self = Self(SDL_WINDOW_OPENGL);
+  pub const VULKAN: Self = Self(SDL_WINDOW_VULKAN);
+  pub const METAL: Self = Self(SDL_WINDOW_METAL);
+  pub const SHOWN: Self = Self(SDL_WINDOW_SHOWN);
+  pub const HIDDEN: Self = Self(SDL_WINDOW_HIDDEN);
+  pub const BORDERLESS: Self = Self(SDL_WINDOW_BORDERLESS);
+  pub const RESIZABLE: Self = Self(SDL_WINDOW_RESIZABLE);
+  pub const MINIMIZED: Self = Self(SDL_WINDOW_MINIMIZED);
+  pub const MAXIMIZED: Self = Self(SDL_WINDOW_MAXIMIZED);
+  pub const INPUT_GRABBED: Self = Self(SDL_WINDOW_INPUT_GRABBED);
+  pub const INPUT_FOCUS: Self = Self(SDL_WINDOW_INPUT_FOCUS);
+  pub const MOUSE_FOCUS: Self = Self(SDL_WINDOW_MOUSE_FOCUS);
+  pub const FOREIGN: Self = Self(SDL_WINDOW_FOREIGN);
+  pub const ALLOW_HIGHDPI: Self = Self(SDL_WINDOW_ALLOW_HIGHDPI);
+  pub const MOUSE_CAPTURE: Self = Self(SDL_WINDOW_MOUSE_CAPTURE);
+  pub const ALWAYS_ON_TOP: Self = Self(SDL_WINDOW_ALWAYS_ON_TOP);
+  pub const SKIP_TASKBAR: Self = Self(SDL_WINDOW_SKIP_TASKBAR);
+  pub const UTILITY: Self = Self(SDL_WINDOW_UTILITY);
+  pub const TOOLTIP: Self = Self(SDL_WINDOW_TOOLTIP);
+  pub const POPUP_MENU: Self = Self(SDL_WINDOW_POPUP_MENU);
+
+  /// If *all* of the bits in `other` are also set in `self`.
+  #[inline]
+  #[must_use]
+  pub const fn contains(self, other: Self) -> bool {
+    (self.0 .0 & other.0 .0) == other.0 .0
+  }
+}
+impl core::ops::BitOr for WindowFlags {
+  type Output = Self;
+  #[inline]
+  fn bitor(self, rhs: Self) -> Self::Output {
+    Self(self.0 | rhs.0)
+  }
+}
+impl core::ops::BitOrAssign for WindowFlags {
+  #[inline]
+  fn bitor_assign(&mut self, rhs: Self) {
+    *self = *self | rhs;
+  }
 }
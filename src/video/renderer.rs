@@ -1,3 +1,5 @@
+use alloc::rc::Rc;
+
 use crate::surface::Surface;
 
 use super::*;
@@ -47,7 +49,7 @@ impl core::fmt::Debug for RendererFlags {
 
 #[derive(Clone, Copy, Default)]
 #[repr(transparent)]
-pub struct PixelFormatEnum(SDL_PixelFormatEnum);
+pub struct PixelFormatEnum(pub(crate) SDL_PixelFormatEnum);
 impl core::fmt::Debug for PixelFormatEnum {
   #[inline]
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -139,31 +141,11 @@ impl Sdl {
   }
 }
 
-#[derive(Clone)]
-#[repr(C)]
-struct Window {
-  win: NonNull<SDL_Window>,
-  parent: Arc<SdlInit>,
-}
-impl Deref for Window {
-  type Target = CommonWindow;
-  #[inline]
-  fn deref(&self) -> &Self::Target {
-    unsafe { &*(self as *const Self).cast::<CommonWindow>() }
-  }
-}
-impl Drop for Window {
-  #[inline]
-  fn drop(&mut self) {
-    unsafe { SDL_DestroyWindow(self.win.as_ptr()) };
-  }
-}
-
 #[derive(Clone)]
 #[repr(C)]
 struct Renderer {
   rend: NonNull<SDL_Renderer>,
-  win: Arc<Window>,
+  win: Rc<Window>,
 }
 impl Deref for Renderer {
   type Target = NonNull<SDL_Renderer>;
@@ -181,8 +163,8 @@ impl Drop for Renderer {
 
 #[repr(C)]
 pub struct RendererWindow {
-  rend: Arc<Renderer>,
-  win: Arc<Window>,
+  rend: Rc<Renderer>,
+  win: Rc<Window>,
   init: Arc<SdlInit>,
 }
 impl Deref for RendererWindow {
@@ -193,29 +175,17 @@ impl Deref for RendererWindow {
   }
 }
 impl Sdl {
-  /// You can only have one GL window active!
+  /// Makes a window along with an SDL renderer for it.
   #[inline]
   pub fn create_renderer_window(
     &self, args: CreateWinArgs<'_>, flags: RendererFlags,
   ) -> Result<RendererWindow, SdlError> {
-    let title_null: String = alloc::format!("{}\0", args.title);
-    let win_p: *mut SDL_Window = unsafe {
-      SDL_CreateWindow(
-        title_null.as_ptr().cast(),
-        SDL_WINDOWPOS_CENTERED,
-        SDL_WINDOWPOS_CENTERED,
-        args.width,
-        args.height,
-        args.window_flags().0,
-      )
-    };
-    let win = match NonNull::new(win_p) {
-      Some(win) => Arc::new(Window { win, parent: self.init.clone() }),
-      None => return Err(get_error()),
-    };
+    let win = args.create_raw_window(SDL_WindowFlags(0))?;
+    let win_p = win.as_ptr();
+    let win = Rc::new(Window { win, init: self.init.clone() });
     let rend_p: *mut SDL_Renderer = unsafe { SDL_CreateRenderer(win_p, -1, flags.0 .0) };
     let rend = match NonNull::new(rend_p) {
-      Some(rend) => Arc::new(Renderer { rend, win: win.clone() }),
+      Some(rend) => Rc::new(Renderer { rend, win: win.clone() }),
       None => return Err(get_error()),
     };
     Ok(RendererWindow { rend, win, init: self.init.clone() })
@@ -409,7 +379,7 @@ pub enum TextureAccess {
 #[repr(C)]
 pub struct Texture {
   tex: NonNull<SDL_Texture>,
-  parent: Arc<Renderer>,
+  parent: Rc<Renderer>,
 }
 impl Drop for Texture {
   #[inline]
@@ -0,0 +1,103 @@
+use super::*;
+use core::num::NonZeroIsize;
+use rwh_06::*;
+
+fn window_handle(win: &CommonWindow) -> Result<WindowHandle<'_>, HandleError> {
+  let info = win.raw_wm_info().map_err(|_| HandleError::Unavailable)?;
+  let missing = || HandleError::Unavailable;
+  let raw = match info.subsystem {
+    SDL_SYSWM_WINDOWS => {
+      let hwnd = NonZeroIsize::new(unsafe { info.info.win.window } as isize).ok_or_else(missing)?;
+      let mut handle = Win32WindowHandle::new(hwnd);
+      handle.hinstance = NonZeroIsize::new(unsafe { info.info.win.hinstance } as isize);
+      RawWindowHandle::Win32(handle)
+    }
+    SDL_SYSWM_WINRT => {
+      let core_window = NonNull::new(unsafe { info.info.winrt.window }).ok_or_else(missing)?;
+      RawWindowHandle::WinRt(WinRtWindowHandle::new(core_window))
+    }
+    SDL_SYSWM_X11 => RawWindowHandle::Xlib(XlibWindowHandle::new(unsafe { info.info.x11.window })),
+    SDL_SYSWM_WAYLAND => {
+      let surface = NonNull::new(unsafe { info.info.wl.surface }).ok_or_else(missing)?;
+      RawWindowHandle::Wayland(WaylandWindowHandle::new(surface))
+    }
+    #[cfg(target_os = "macos")]
+    SDL_SYSWM_COCOA => {
+      use objc::{msg_send, runtime::Object, sel, sel_impl};
+      let ns_window = unsafe { info.info.cocoa.window }.cast::<Object>();
+      let ns_view: *mut Object = unsafe { msg_send![ns_window, contentView] };
+      let ns_view = NonNull::new(ns_view.cast::<c_void>()).ok_or_else(missing)?;
+      RawWindowHandle::AppKit(AppKitWindowHandle::new(ns_view))
+    }
+    #[cfg(target_os = "ios")]
+    SDL_SYSWM_UIKIT => {
+      use objc::{msg_send, runtime::Object, sel, sel_impl};
+      let ui_window = unsafe { info.info.uikit.window }.cast::<Object>();
+      let controller: *mut Object = unsafe { msg_send![ui_window, rootViewController] };
+      let ui_view: *mut Object = unsafe { msg_send![controller, view] };
+      let ui_view = NonNull::new(ui_view.cast::<c_void>()).ok_or_else(missing)?;
+      RawWindowHandle::UiKit(UiKitWindowHandle::new(ui_view))
+    }
+    SDL_SYSWM_ANDROID => {
+      let a_native_window =
+        NonNull::new(unsafe { info.info.android.window }).ok_or_else(missing)?;
+      RawWindowHandle::AndroidNdk(AndroidNdkWindowHandle::new(a_native_window))
+    }
+    _ => return Err(HandleError::NotSupported),
+  };
+  // Safety: the handle is only valid while the window is, and it borrows the
+  // window.
+  Ok(unsafe { WindowHandle::borrow_raw(raw) })
+}
+
+fn display_handle(win: &CommonWindow) -> Result<DisplayHandle<'_>, HandleError> {
+  let info = win.raw_wm_info().map_err(|_| HandleError::Unavailable)?;
+  let raw = match info.subsystem {
+    SDL_SYSWM_WINDOWS | SDL_SYSWM_WINRT => RawDisplayHandle::Windows(WindowsDisplayHandle::new()),
+    // SDL doesn't say which screen the window is on, so this gives the
+    // default screen.
+    SDL_SYSWM_X11 => RawDisplayHandle::Xlib(XlibDisplayHandle::new(
+      NonNull::new(unsafe { info.info.x11.display }),
+      0,
+    )),
+    SDL_SYSWM_WAYLAND => {
+      let display =
+        NonNull::new(unsafe { info.info.wl.display }).ok_or(HandleError::Unavailable)?;
+      RawDisplayHandle::Wayland(WaylandDisplayHandle::new(display))
+    }
+    SDL_SYSWM_COCOA => RawDisplayHandle::AppKit(AppKitDisplayHandle::new()),
+    SDL_SYSWM_UIKIT => RawDisplayHandle::UiKit(UiKitDisplayHandle::new()),
+    SDL_SYSWM_ANDROID => RawDisplayHandle::Android(AndroidDisplayHandle::new()),
+    _ => return Err(HandleError::NotSupported),
+  };
+  Ok(unsafe { DisplayHandle::borrow_raw(raw) })
+}
+
+macro_rules! impl_rwh {
+  ($($(#[$m:meta])* $t:ty),+ $(,)?) => {
+    $(
+      $(#[$m])*
+      impl HasWindowHandle for $t {
+        #[inline]
+        fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+          window_handle(self)
+        }
+      }
+      $(#[$m])*
+      impl HasDisplayHandle for $t {
+        #[inline]
+        fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+          display_handle(self)
+        }
+      }
+    )+
+  };
+}
+
+impl_rwh!(
+  Window,
+  GlWindow,
+  RendererWindow,
+  #[cfg(feature = "vulkan")]
+  VkWindow,
+);
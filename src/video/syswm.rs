@@ -0,0 +1,70 @@
+use super::*;
+
+/// The platform's handles for a window, from [`CommonWindow::wm_info`].
+///
+/// These are the raw platform values, so using them is up to you.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WmInfo {
+  /// `HWND`, `HDC`, and `HINSTANCE`.
+  Windows { hwnd: *mut c_void, hdc: *mut c_void, hinstance: *mut c_void },
+  /// An `IInspectable*` for the `CoreWindow`.
+  WinRt { core_window: *mut c_void },
+  /// `Display*` and `Window`.
+  X11 { display: *mut c_void, window: c_ulong },
+  /// `IDirectFB*`, `IDirectFBWindow*`, and `IDirectFBSurface*`.
+  DirectFb { dfb: *mut c_void, window: *mut c_void, surface: *mut c_void },
+  /// `NSWindow*`.
+  Cocoa { ns_window: *mut c_void },
+  /// `UIWindow*`, plus the GL objects that SDL renders with.
+  UiKit { ui_window: *mut c_void, framebuffer: u32, colorbuffer: u32, resolve_framebuffer: u32 },
+  /// `wl_display*`, `wl_surface*`, and `wl_shell_surface*`.
+  Wayland { display: *mut c_void, surface: *mut c_void, shell_surface: *mut c_void },
+  /// `ANativeWindow*` and `EGLSurface`.
+  Android { native_window: *mut c_void, egl_surface: *mut c_void },
+  /// `EGLNativeDisplayType` and `EGLNativeWindowType`.
+  Vivante { display: *mut c_void, window: *mut c_void },
+  /// A system that beryllium doesn't know the handles of.
+  ///
+  /// * `subsystem` is the raw `SDL_SYSWM_TYPE` value.
+  Other { subsystem: i32 },
+}
+
+impl CommonWindow {
+  /// Gets the platform's handles for the window.
+  #[inline]
+  pub fn wm_info(&self) -> Result<WmInfo, SdlError> {
+    let info = self.raw_wm_info()?;
+    let i = &info.info;
+    Ok(unsafe {
+      match info.subsystem {
+        SDL_SYSWM_WINDOWS => {
+          WmInfo::Windows { hwnd: i.win.window, hdc: i.win.hdc, hinstance: i.win.hinstance }
+        }
+        SDL_SYSWM_WINRT => WmInfo::WinRt { core_window: i.winrt.window },
+        SDL_SYSWM_X11 => WmInfo::X11 { display: i.x11.display, window: i.x11.window },
+        SDL_SYSWM_DIRECTFB => {
+          WmInfo::DirectFb { dfb: i.dfb.dfb, window: i.dfb.window, surface: i.dfb.surface }
+        }
+        SDL_SYSWM_COCOA => WmInfo::Cocoa { ns_window: i.cocoa.window },
+        SDL_SYSWM_UIKIT => WmInfo::UiKit {
+          ui_window: i.uikit.window,
+          framebuffer: i.uikit.framebuffer,
+          colorbuffer: i.uikit.colorbuffer,
+          resolve_framebuffer: i.uikit.resolveFramebuffer,
+        },
+        SDL_SYSWM_WAYLAND => WmInfo::Wayland {
+          display: i.wl.display,
+          surface: i.wl.surface,
+          shell_surface: i.wl.shell_surface,
+        },
+        SDL_SYSWM_ANDROID => {
+          WmInfo::Android { native_window: i.android.window, egl_surface: i.android.surface }
+        }
+        SDL_SYSWM_VIVANTE => {
+          WmInfo::Vivante { display: i.vivante.display, window: i.vivante.window }
+        }
+        other => WmInfo::Other { subsystem: other.0 },
+      }
+    })
+  }
+}
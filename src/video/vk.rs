@@ -1,4 +1,4 @@
-use core::ptr::null_mut;
+use core::{marker::PhantomData, ptr::null_mut};
 use raw_vulkan_handle::*;
 use zstring::{ZStr, ZString};
 
@@ -17,21 +17,8 @@ pub struct VkWindow {
 impl Sdl {
   #[inline]
   pub fn create_vk_window(&self, args: CreateWinArgs<'_>) -> Result<VkWindow, SdlError> {
-    let title_null: String = alloc::format!("{}\0", args.title);
-    let win_p: *mut SDL_Window = unsafe {
-      SDL_CreateWindow(
-        title_null.as_ptr().cast(),
-        SDL_WINDOWPOS_CENTERED,
-        SDL_WINDOWPOS_CENTERED,
-        args.width,
-        args.height,
-        SDL_WINDOW_VULKAN.0 | args.window_flags().0,
-      )
-    };
-    match NonNull::new(win_p) {
-      Some(win) => Ok(VkWindow { win, init: self.init.clone() }),
-      None => Err(get_error()),
-    }
+    let win = args.create_raw_window(SDL_WINDOW_VULKAN)?;
+    Ok(VkWindow { win, init: self.init.clone() })
   }
 }
 impl Drop for VkWindow {
@@ -51,6 +38,111 @@ impl Deref for VkWindow {
 #[allow(non_camel_case_types)]
 pub type PFN_vkVoidFunction = Option<unsafe extern "system" fn()>;
 
+/// Keeps a Vulkan loader loaded, from [`Sdl::vulkan_load_library`].
+///
+/// SDL counts library loads, and each Vulkan window holds a load of its own,
+/// so dropping this never unloads the loader out from under a live window.
+/// Anything gotten through the loader (such as an instance) must still be
+/// destroyed before this is dropped.
+#[must_use = "the loader is unloaded when this is dropped"]
+pub struct VulkanLibrary {
+  #[allow(dead_code)]
+  init: Arc<SdlInit>,
+}
+impl Drop for VulkanLibrary {
+  #[inline]
+  fn drop(&mut self) {
+    unsafe { SDL_Vulkan_UnloadLibrary() }
+  }
+}
+
+/// Gets the instance extensions SDL needs for `win`, or for any window if
+/// `win` is null.
+fn get_instance_extensions(win: *mut SDL_Window) -> Result<Vec<ZString>, SdlError> {
+  let mut count: c_uint = 0;
+  let success: bool =
+    unsafe { SDL_Vulkan_GetInstanceExtensions(win, &mut count, null_mut()) }.into();
+  if !success {
+    return Err(get_error());
+  }
+  let mut buf: Vec<ZStr<'_>> = Vec::with_capacity(count.try_into().unwrap());
+  let success: bool =
+    unsafe { SDL_Vulkan_GetInstanceExtensions(win, &mut count, buf.as_mut_ptr().cast()) }.into();
+  if !success {
+    return Err(get_error());
+  }
+  unsafe {
+    buf.set_len(count.try_into().unwrap());
+  }
+  Ok(buf.into_iter().map(ZString::from).collect())
+}
+
+impl Sdl {
+  /// Loads a Vulkan loader library.
+  ///
+  /// Passing `None` loads the system's default loader. This happens
+  /// automatically when the first Vulkan window is made, so you only need to
+  /// call this if you want to pick a specific loader, or want to set up an
+  /// instance before making a window. Once a loader is loaded, calling this
+  /// again with `None` or the same path is fine, but a different path is an
+  /// error.
+  ///
+  /// The loader stays loaded until the returned [`VulkanLibrary`] is dropped
+  /// *and* all Vulkan windows are gone.
+  #[inline]
+  pub fn vulkan_load_library(&self, path: Option<&str>) -> Result<VulkanLibrary, SdlError> {
+    let path_null = path.map(|path| alloc::format!("{path}\0"));
+    let path_p = path_null.as_ref().map_or(core::ptr::null(), |p| p.as_ptr().cast());
+    if 0 == unsafe { SDL_Vulkan_LoadLibrary(path_p) } {
+      Ok(VulkanLibrary { init: self.init.clone() })
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Gets `vkGetInstanceProcAddr` from the loaded Vulkan loader.
+  ///
+  /// This is `None` until a loader is loaded.
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn get_vkGetInstanceProcAddr(
+    &self,
+  ) -> Option<unsafe extern "system" fn(VkInstance, *const u8) -> PFN_vkVoidFunction> {
+    unsafe { core::mem::transmute(SDL_Vulkan_GetVkGetInstanceProcAddr()) }
+  }
+
+  /// Gets the list of extensions required during vulkan instance creation to
+  /// make it work with any window.
+  ///
+  /// A Vulkan loader has to be loaded first, either with
+  /// [`vulkan_load_library`](Sdl::vulkan_load_library) or by making a Vulkan
+  /// window.
+  #[inline]
+  pub fn get_vulkan_instance_extensions(&self) -> Result<Vec<ZString>, SdlError> {
+    get_instance_extensions(null_mut())
+  }
+
+  /// Checks that a list of instance extensions has all of the extensions
+  /// that SDL needs.
+  ///
+  /// The error lists any missing extensions.
+  #[inline]
+  pub fn check_vulkan_instance_extensions(&self, enabled: &[&str]) -> Result<(), SdlError> {
+    let missing: Vec<ZString> = self
+      .get_vulkan_instance_extensions()?
+      .into_iter()
+      .filter(|required| !enabled.iter().any(|e| required == e))
+      .collect();
+    if missing.is_empty() {
+      Ok(())
+    } else {
+      Err(SdlError::new(&alloc::format!(
+        "beryllium: missing required Vulkan instance extensions: {missing:?}"
+      )))
+    }
+  }
+}
+
 impl VkWindow {
   #[inline]
   #[allow(non_snake_case)]
@@ -64,24 +156,7 @@ impl VkWindow {
   /// make it work with this window.
   #[inline]
   pub fn get_instance_extensions(&self) -> Result<Vec<ZString>, SdlError> {
-    let mut count: c_uint = 0;
-    let success: bool =
-      unsafe { SDL_Vulkan_GetInstanceExtensions(self.win.as_ptr(), &mut count, null_mut()) }.into();
-    if !success {
-      return Err(get_error());
-    }
-    let mut buf: Vec<ZStr<'_>> = Vec::with_capacity(count.try_into().unwrap());
-    let success: bool = unsafe {
-      SDL_Vulkan_GetInstanceExtensions(self.win.as_ptr(), &mut count, buf.as_mut_ptr().cast())
-    }
-    .into();
-    if !success {
-      return Err(get_error());
-    }
-    unsafe {
-      buf.set_len(count.try_into().unwrap());
-    }
-    Ok(buf.into_iter().map(ZString::from).collect())
+    get_instance_extensions(self.win.as_ptr())
   }
 
   /// Creates a surface for this window.
@@ -100,6 +175,34 @@ impl VkWindow {
     }
   }
 
+  /// Creates a surface for this window that destroys itself when dropped.
+  ///
+  /// The surface borrows the window, so it can't outlive the window.
+  ///
+  /// ## Safety
+  /// * The `instance` needs to be a valid instance with the surface creation
+  ///   extension enabled, same as with [`create_surface`](Self::create_surface).
+  /// * The `instance` must not be destroyed until after the surface is
+  ///   dropped.
+  #[inline]
+  pub unsafe fn create_window_surface(
+    &self, instance: VkInstance,
+  ) -> Result<VkWindowSurface<'_>, SdlError> {
+    const VK_DESTROY_SURFACE_KHR: &[u8] = b"vkDestroySurfaceKHR\0";
+    let get_instance_proc_addr = self
+      .get_vkGetInstanceProcAddr()
+      .ok_or_else(|| SdlError::new("beryllium: no `vkGetInstanceProcAddr` available."))?;
+    let destroy: PFN_vkDestroySurfaceKHR =
+      match unsafe { get_instance_proc_addr(instance, VK_DESTROY_SURFACE_KHR.as_ptr()) } {
+        Some(f) => unsafe {
+          core::mem::transmute::<unsafe extern "system" fn(), PFN_vkDestroySurfaceKHR>(f)
+        },
+        None => return Err(SdlError::new("beryllium: no `vkDestroySurfaceKHR` available.")),
+      };
+    let surface = unsafe { self.create_surface(instance)? };
+    Ok(VkWindowSurface { surface, instance, destroy, win: PhantomData })
+  }
+
   /// Get the size of a window's underlying drawable area in pixels (for use
   /// with setting viewport, scissor & etc).
   #[inline]
@@ -110,3 +213,36 @@ impl VkWindow {
     (w, h)
   }
 }
+
+/// Khronos: [vkDestroySurfaceKHR](https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/vkDestroySurfaceKHR.html)
+#[allow(non_camel_case_types)]
+pub type PFN_vkDestroySurfaceKHR =
+  unsafe extern "system" fn(VkInstance, VkSurfaceKHR, *const c_void);
+
+/// A Vulkan surface for a [`VkWindow`], which is destroyed when dropped.
+///
+/// Made with [`VkWindow::create_window_surface`].
+pub struct VkWindowSurface<'w> {
+  surface: VkSurfaceKHR,
+  instance: VkInstance,
+  destroy: PFN_vkDestroySurfaceKHR,
+  win: PhantomData<&'w VkWindow>,
+}
+impl VkWindowSurface<'_> {
+  #[inline]
+  pub fn surface(&self) -> VkSurfaceKHR {
+    self.surface
+  }
+
+  /// The instance that the surface was made with.
+  #[inline]
+  pub fn instance(&self) -> VkInstance {
+    self.instance
+  }
+}
+impl Drop for VkWindowSurface<'_> {
+  #[inline]
+  fn drop(&mut self) {
+    unsafe { (self.destroy)(self.instance, self.surface, core::ptr::null()) }
+  }
+}
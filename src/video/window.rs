@@ -0,0 +1,132 @@
+use super::*;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// A window without any graphics API attached.
+///
+/// You can draw to it on the CPU through its
+/// [window surface](Window::get_window_surface), or hand it to an outside
+/// renderer through its window handles.
+#[repr(C)]
+pub struct Window {
+  pub(crate) win: NonNull<SDL_Window>,
+  /// Note(Lokathor): The init is always the LAST field!
+  pub(crate) init: Arc<SdlInit>,
+}
+impl Sdl {
+  #[inline]
+  pub fn create_window(&self, args: CreateWinArgs<'_>) -> Result<Window, SdlError> {
+    let win = args.create_raw_window(SDL_WindowFlags(0))?;
+    Ok(Window { win, init: self.init.clone() })
+  }
+}
+impl Drop for Window {
+  #[inline]
+  fn drop(&mut self) {
+    unsafe { SDL_DestroyWindow(self.win.as_ptr()) }
+  }
+}
+impl Deref for Window {
+  type Target = CommonWindow;
+  #[inline]
+  fn deref(&self) -> &Self::Target {
+    unsafe { &*(self as *const Self).cast::<CommonWindow>() }
+  }
+}
+impl Window {
+  /// Gets the surface for drawing to the window on the CPU.
+  ///
+  /// The surface is remade by SDL when the window changes size, so get it
+  /// again each frame rather than keeping it around. Changes only show up
+  /// once you call [`update_window_surface`](Window::update_window_surface).
+  ///
+  /// A window that uses its surface can't also be used with GL, Vulkan, or
+  /// an `SDL_Renderer`.
+  #[inline]
+  pub fn get_window_surface(&mut self) -> Result<WindowSurface<'_>, SdlError> {
+    match NonNull::new(unsafe { SDL_GetWindowSurface(self.win.as_ptr()) }) {
+      Some(surf) => Ok(WindowSurface { surf, win: PhantomData }),
+      None => Err(get_error()),
+    }
+  }
+
+  /// Copies the whole window surface to the screen.
+  #[inline]
+  pub fn update_window_surface(&self) -> Result<(), SdlError> {
+    if 0 == unsafe { SDL_UpdateWindowSurface(self.win.as_ptr()) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+
+  /// Copies just some areas of the window surface to the screen.
+  ///
+  /// Each rect is `[x, y, w, h]`.
+  #[inline]
+  pub fn update_window_surface_rects(&self, rects: &[[c_int; 4]]) -> Result<(), SdlError> {
+    let rects: Vec<SDL_Rect> = rects.iter().map(|&[x, y, w, h]| SDL_Rect { x, y, w, h }).collect();
+    let count = c_int::try_from(rects.len()).map_err(|_| SdlError::new("too many rects"))?;
+    if 0 == unsafe { SDL_UpdateWindowSurfaceRects(self.win.as_ptr(), rects.as_ptr(), count) } {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+}
+
+/// The CPU-side pixels of a [`Window`].
+pub struct WindowSurface<'w> {
+  surf: NonNull<SDL_Surface>,
+  win: PhantomData<&'w mut Window>,
+}
+impl WindowSurface<'_> {
+  #[inline]
+  pub fn width(&self) -> i32 {
+    unsafe { self.surf.as_ref().w }
+  }
+
+  #[inline]
+  pub fn height(&self) -> i32 {
+    unsafe { self.surf.as_ref().h }
+  }
+
+  /// The number of bytes from the start of one row of pixels to the next.
+  #[inline]
+  pub fn pitch(&self) -> i32 {
+    unsafe { self.surf.as_ref().pitch }
+  }
+
+  #[inline]
+  pub fn format(&self) -> PixelFormatEnum {
+    PixelFormatEnum(SDL_PixelFormatEnum(unsafe { (*self.surf.as_ref().format).format }))
+  }
+
+  /// The raw pixel bytes, `pitch * height` in total.
+  ///
+  /// The layout of each pixel depends on the [`format`](Self::format).
+  #[inline]
+  pub fn pixels_mut(&mut self) -> &mut [u8] {
+    let s = unsafe { self.surf.as_ref() };
+    let len = (s.pitch as usize) * (s.h as usize);
+    unsafe { core::slice::from_raw_parts_mut(s.pixels.cast::<u8>(), len) }
+  }
+
+  /// Copies all of a surface onto this surface, with its upper left corner at
+  /// `(x, y)`.
+  ///
+  /// Pixel format conversion happens automatically.
+  #[inline]
+  pub fn blit(&mut self, src: &Surface, x: i32, y: i32) -> Result<(), SdlError> {
+    let mut dst_rect = SDL_Rect { x, y, w: 0, h: 0 };
+    if 0
+      == unsafe {
+        SDL_BlitSurface(src.surf.as_ptr(), core::ptr::null(), self.surf.as_ptr(), &mut dst_rect)
+      }
+    {
+      Ok(())
+    } else {
+      Err(get_error())
+    }
+  }
+}